@@ -1,23 +1,67 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::cell::RefCell;
 
-use crate::chip::{Chip, ChipInterface, Bus, Pin};
-use crate::chip::pin::{ConstantPin, is_constant_pin};
+use crate::chip::{Chip, ChipInterface, Bus, Pin, Connection, PinSide, WireError};
+use crate::chip::pin::is_constant_pin;
 use crate::chip::builtins::*;
-use crate::languages::hdl::{HdlChip, PinDecl, Part, Wire, WireSide};
+use crate::languages::hdl::{HdlChip, LintWarning, PinDecl, Part, Wire, WireSide};
 use crate::error::{Result, SimulatorError};
 
 // Pin type methods are now implemented by the builtins using their own macros
 
+// Builtins that implement ClockedChip and so need clock edges, by registry name
+const CLOCKED_BUILTINS: &[&str] = &[
+    "DFF", "Bit", "Register", "EnabledRegister", "PC", "Counter",
+    "RAM8", "RAM64", "SyncRAM64", "RAM512", "RAM4K", "RAM16K",
+    "ProgRAM", "Screen", "Computer",
+];
+
+// Width-suffixed builtins that are clocked at every width, e.g. "Register8"
+const CLOCKED_SIZED_BUILTINS: &[&str] = &["Register", "RegisterFile"];
+
+/// Non-fatal finding reported by `ChipBuilder::build_chip_verbose`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// Informational: an internal pin took its width from the part pins it connects to
+    InferredWidth { chip: String, pin: String, width: usize },
+    /// Warning: wiring that builds but is probably a mistake
+    Lint(LintWarning),
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::InferredWidth { chip, pin, width } => {
+                write!(f, "Internal pin '{}' in chip {} inferred as {} bit(s) wide", pin, chip, width)
+            }
+            Diagnostic::Lint(warning) => write!(f, "{}", warning),
+        }
+    }
+}
+
 pub struct ChipBuilder {
     builtin_registry: HashMap<String, Box<dyn Fn() -> Box<dyn ChipInterface>>>,
+    // Reject internal pins that are never both driven and consumed (likely typos)
+    strict: bool,
+    // Fail combinational builtin evals that would read an undriven input
+    strict_floating: bool,
+    // HDL definitions that parts resolve to before the builtins, and the chips mid-build
+    library: HashMap<String, HdlChip>,
+    building: RefCell<Vec<String>>,
+    // Collects build diagnostics while build_chip_verbose runs
+    diagnostics: RefCell<Option<Vec<Diagnostic>>>,
 }
 
 impl ChipBuilder {
     pub fn new() -> Self {
         let mut builder = Self {
             builtin_registry: HashMap::new(),
+            strict: false,
+            strict_floating: false,
+            library: HashMap::new(),
+            building: RefCell::new(Vec::new()),
+            diagnostics: RefCell::new(None),
         };
         
         // Register builtin chips
@@ -25,9 +69,42 @@ impl ChipBuilder {
         builder
     }
     
+    /// Enable strict mode, where an internal pin that is only driven or only consumed is an error
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+    
+    /// Make every combinational builtin built from here on fail its eval with `FloatingInput`
+    /// when an input was never driven, rather than reading it as LOW
+    /// Clocked builtins are left alone: their eval only reads state, and feedback through them
+    /// legitimately leaves inputs undriven until the loop has settled once
+    pub fn strict_floating(mut self, strict: bool) -> Self {
+        self.strict_floating = strict;
+        self
+    }
+    
+    /// Back every "ROM32K" part built from here on with a ROM preloaded with `words`
+    pub fn with_rom_program(mut self, words: Vec<u16>) -> Self {
+        self.builtin_registry.insert("ROM32K".to_string(), Box::new(move || {
+            Box::new(Rom32kChip::with_program(words.clone()))
+        }));
+        self
+    }
+    
+    /// Resolve parts named after these chips to their HDL definitions instead of the builtins
+    /// Definitions marked BUILTIN still build the builtin
+    pub fn with_library(mut self, chips: impl IntoIterator<Item = HdlChip>) -> Self {
+        self.library.extend(chips.into_iter().map(|chip| (chip.name.clone(), chip)));
+        self
+    }
+    
     pub fn build_chip(&self, hdl_chip: &HdlChip) -> Result<Box<dyn ChipInterface>> {
         if hdl_chip.is_builtin {
-            return self.build_builtin_chip(&hdl_chip.name);
+            let builtin = hdl_chip.builtin_name.as_deref().unwrap_or(&hdl_chip.name);
+            let chip = self.build_builtin_chip(builtin)?;
+            self.check_builtin_header(hdl_chip, chip.as_ref())?;
+            return Ok(chip);
         }
         
         let mut chip = Chip::new(hdl_chip.name.clone());
@@ -50,6 +127,17 @@ impl ChipBuilder {
         Ok(Box::new(chip))
     }
     
+    /// Build like `build_chip`, also returning what the build noticed along the way: the width
+    /// inferred for each internal pin (of library parts too) followed by the lint warnings
+    /// Diagnostics gathered before a failure are still returned
+    pub fn build_chip_verbose(&self, hdl_chip: &HdlChip) -> (Result<Box<dyn ChipInterface>>, Vec<Diagnostic>) {
+        self.diagnostics.replace(Some(Vec::new()));
+        let result = self.build_chip(hdl_chip);
+        let mut diagnostics = self.diagnostics.take().unwrap_or_default();
+        diagnostics.extend(self.lint(hdl_chip).into_iter().map(Diagnostic::Lint));
+        (result, diagnostics)
+    }
+    
     fn create_pin_from_decl(&self, pin_decl: &PinDecl) -> Result<Rc<RefCell<dyn Pin>>> {
         let width = pin_decl.width.unwrap_or(1) as usize;
         let bus = Bus::new(pin_decl.name.clone(), width);
@@ -57,106 +145,372 @@ impl ChipBuilder {
     }
     
     fn build_parts(&self, chip: &mut Chip, parts: &[Part]) -> Result<()> {
-        // Track all internal pins needed
-        let mut internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>> = HashMap::new();
-        
-        // First pass: identify all internal pins
+        // Build every part up front so internal pins can take their width from the part pins they touch
+        let mut sub_chips = Vec::with_capacity(parts.len());
         for part in parts {
-            for wire in &part.connections {
-                self.collect_internal_pins(&mut internal_pins, &wire.from, chip)?;
-                self.collect_internal_pins(&mut internal_pins, &wire.to, chip)?;
-            }
+            let sub_chip = self.build_part(&part.name)?;
+            self.check_repeated_inputs(part, sub_chip.as_ref())?;
+            sub_chips.push(sub_chip);
+        }
+        
+        let internal_widths = self.infer_internal_widths(chip, parts, &sub_chips)?;
+        
+        if self.strict {
+            self.check_internal_pin_usage(chip, parts, &sub_chips, &internal_widths)?;
+        }
+        
+        if let Some(diagnostics) = self.diagnostics.borrow_mut().as_mut() {
+            let mut inferred: Vec<(&String, &usize)> = internal_widths.iter().collect();
+            inferred.sort();
+            diagnostics.extend(inferred.into_iter().map(|(pin, width)| Diagnostic::InferredWidth {
+                chip: chip.name().to_string(),
+                pin: pin.clone(),
+                width: *width,
+            }));
         }
         
         // Add internal pins to chip
-        for (name, pin) in internal_pins {
-            chip.add_internal_pin(name, pin);
+        for (name, width) in internal_widths {
+            let bus = Bus::new(name.clone(), width);
+            chip.add_internal_pin(name, Rc::new(RefCell::new(bus)));
         }
         
-        // Second pass: build sub-chips and connect them
-        for part in parts {
-            let sub_chip = self.build_builtin_chip(&part.name)?;
-            self.connect_part(chip, sub_chip.as_ref(), &part.connections)?;
-            chip.add_sub_chip(sub_chip);
+        // Second pass: wire each part to the host chip's pins, registering sequential
+        // parts for clock edges (builtins by name, library chips by what they contain)
+        for (part, mut sub_chip) in parts.iter().zip(sub_chips) {
+            let connections = part.connections.iter()
+                .map(|wire| self.wire_to_connection(wire))
+                .collect::<Result<Vec<_>>>()?;
+            let clocked = Self::is_clocked(&part.name) || sub_chip.as_clocked_mut().is_some();
+            chip.wire_part(sub_chip, connections, clocked)
+                .map_err(|e| SimulatorError::Hardware(format!("Failed to wire part {}: {}", part.name, e)))?;
         }
         
+        chip.check_combinational_loops()?;
+        
         Ok(())
     }
     
-    fn collect_internal_pins(
-        &self,
-        internal_pins: &mut HashMap<String, Rc<RefCell<dyn Pin>>>,
-        wire_side: &WireSide,
-        chip: &Chip,
-    ) -> Result<()> {
-        if let WireSide::Pin { name, .. } = wire_side {
-            // Check if this pin is already an input or output
-            if chip.input_pins().contains_key(name) || chip.output_pins().contains_key(name) {
-                return Ok(());
+    // A part from the library if one is defined under that name, otherwise a builtin
+    fn build_part(&self, name: &str) -> Result<Box<dyn ChipInterface>> {
+        let Some(definition) = self.library.get(name).filter(|chip| !chip.is_builtin) else {
+            return self.build_builtin_chip(name);
+        };
+        
+        if self.building.borrow().iter().any(|outer| outer == name) {
+            return Err(SimulatorError::Hardware(format!("Chip {} is built from itself", name)));
+        }
+        self.building.borrow_mut().push(name.to_string());
+        let chip = self.build_chip(definition);
+        self.building.borrow_mut().pop();
+        chip
+    }
+    
+    // A part input (or any bit of one) assigned twice, e.g. `And(a=x, a=y, ...)`, is an error
+    // rather than last-one-wins; outputs may legitimately fan out to several host pins
+    fn check_repeated_inputs(&self, part: &Part, sub_chip: &dyn ChipInterface) -> Result<()> {
+        let mut assigned: HashMap<&str, u32> = HashMap::new();
+        for wire in &part.connections {
+            let WireSide::Pin { name, range } = &wire.to else { continue };
+            if !sub_chip.is_input_pin(name) {
+                continue;
             }
             
-            // Check if it's a constant
-            if is_constant_pin(name) {
-                return Ok(());
-            }
+            let (start, end) = match range {
+                Some(range) if !range.is_full_pin() => (range.start_index(), range.end_index()),
+                _ => (0, sub_chip.pin_width(name)?.saturating_sub(1)),
+            };
+            let bits = (start..=end.min(31)).fold(0u32, |mask, bit| mask | 1 << bit);
             
-            // Add as internal pin if not already present
-            if !internal_pins.contains_key(name) {
-                let bus = Bus::new(name.clone(), 1); // Default width, will be adjusted if needed
-                internal_pins.insert(name.clone(), Rc::new(RefCell::new(bus)));
+            let seen = assigned.entry(name.as_str()).or_insert(0);
+            if *seen & bits != 0 {
+                return Err(WireError::MultipleAssignment {
+                    pin_name: name.clone(),
+                    conflict: format!("part {} connects input '{}' more than once", part.name, name),
+                }.into());
             }
+            *seen |= bits;
         }
-        
         Ok(())
     }
     
-    fn connect_part(
+    // Internal pins are as wide as the part pins they are wired to whole; pins only ever used
+    // through slices take the width their highest slice needs (tmp[8..15] needs 16 bits).
+    // A pin wired both ways keeps its part-pin width, which must cover every slice.
+    fn infer_internal_widths(
         &self,
         chip: &Chip,
-        _sub_chip: &dyn ChipInterface,
-        connections: &[Wire],
-    ) -> Result<()> {
-        for wire in connections {
-            let from_pin = self.resolve_wire_side(chip, &wire.from)?;
-            let to_pin = self.resolve_wire_side(chip, &wire.to)?;
-            
-            // Connect the pins
-            let weak_to = Rc::downgrade(&to_pin);
-            from_pin.borrow_mut().connect(weak_to);
+        parts: &[Part],
+        sub_chips: &[Box<dyn ChipInterface>],
+    ) -> Result<HashMap<String, usize>> {
+        let mut whole_widths: HashMap<String, usize> = HashMap::new();
+        let mut slice_widths: HashMap<String, usize> = HashMap::new();
+        for (part, sub_chip) in parts.iter().zip(sub_chips) {
+            for wire in &part.connections {
+                // Only the host side of a wire names host pins; the other side is the part's own pin
+                let WireSide::Pin { name, range } = &wire.from else { continue };
+                if chip.input_pins().contains_key(name) || chip.output_pins().contains_key(name) || is_constant_pin(name) {
+                    continue;
+                }
+                
+                let (widths, width) = match range.as_ref().filter(|range| !range.is_full_pin()) {
+                    Some(range) => (&mut slice_widths, range.end_index() + 1),
+                    None => {
+                        let width = match &wire.to {
+                            WireSide::Pin { range: Some(part_range), .. } => part_range.width(),
+                            WireSide::Pin { name: part_pin, range: None } => sub_chip.get_pin(part_pin)
+                                .map(|pin| pin.borrow().width())
+                                .unwrap_or(1),
+                            _ => 1,
+                        };
+                        (&mut whole_widths, width)
+                    }
+                };
+                let entry = widths.entry(name.clone()).or_insert(width);
+                *entry = (*entry).max(width);
+            }
         }
         
-        Ok(())
+        let mut slice_widths: Vec<(String, usize)> = slice_widths.into_iter().collect();
+        slice_widths.sort();
+        for (name, needed) in slice_widths {
+            match whole_widths.get(&name) {
+                Some(&width) if width < needed => {
+                    return Err(SimulatorError::Hardware(format!(
+                        "Internal pin '{}' in chip {} is {} bits wide from the part pins it connects to, but its slices need {} bits",
+                        name, chip.name(), width, needed
+                    )));
+                }
+                Some(_) => {}
+                None => {
+                    whole_widths.insert(name, needed);
+                }
+            }
+        }
+        Ok(whole_widths)
     }
     
-    fn resolve_wire_side(
+    fn check_internal_pin_usage(
         &self,
         chip: &Chip,
-        wire_side: &WireSide,
-    ) -> Result<Rc<RefCell<dyn Pin>>> {
-        match wire_side {
-            WireSide::Pin { name, range: _ } => {
-                // Check constants first
-                if is_constant_pin(name) {
-                    let constant = ConstantPin::new(name.clone())?;
-                    return Ok(Rc::new(RefCell::new(constant)));
+        parts: &[Part],
+        sub_chips: &[Box<dyn ChipInterface>],
+        internal_widths: &HashMap<String, usize>,
+    ) -> Result<()> {
+        // (driven, consumed) for each internal pin
+        let mut usage: HashMap<&str, (bool, bool)> = HashMap::new();
+        for (part, sub_chip) in parts.iter().zip(sub_chips) {
+            for wire in &part.connections {
+                if let (WireSide::Pin { name, .. }, WireSide::Pin { name: part_pin, .. }) = (&wire.from, &wire.to) {
+                    if internal_widths.contains_key(name) {
+                        let flags = usage.entry(name.as_str()).or_insert((false, false));
+                        if sub_chip.is_output_pin(part_pin) {
+                            flags.0 = true;
+                        } else {
+                            flags.1 = true;
+                        }
+                    }
+                }
+            }
+        }
+        
+        let mut declared: Vec<&str> = chip.input_pins().keys()
+            .chain(chip.output_pins().keys())
+            .map(|name| name.as_str())
+            .collect();
+        declared.sort();
+        
+        let mut names: Vec<&&str> = usage.keys().collect();
+        names.sort();
+        for name in names {
+            let (driven, consumed) = usage[*name];
+            if !(driven && consumed) {
+                let problem = if driven { "driven but never read" } else { "read but never driven" };
+                return Err(SimulatorError::Hardware(format!(
+                    "Internal pin '{}' in chip {} is {} (possible typo; declared pins: {})",
+                    name, chip.name(), problem, declared.join(", ")
+                )));
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Warn about declared inputs no part reads, declared outputs no part drives and internal
+    /// pins driven but never read. Part pin directions come from this builder's builtins; wires
+    /// to a part it can't build count as both reading and driving, so they never warn
+    pub fn lint(&self, hdl_chip: &HdlChip) -> Vec<LintWarning> {
+        if hdl_chip.is_builtin {
+            return Vec::new();
+        }
+        
+        let mut read: HashSet<&str> = HashSet::new();
+        let mut driven: HashSet<&str> = HashSet::new();
+        for part in &hdl_chip.parts {
+            let sub_chip = self.build_part(&part.name).ok();
+            for wire in &part.connections {
+                if let (WireSide::Pin { name, .. }, WireSide::Pin { name: part_pin, .. }) = (&wire.from, &wire.to) {
+                    match &sub_chip {
+                        Some(sub_chip) if sub_chip.is_output_pin(part_pin) => { driven.insert(name); }
+                        Some(_) => { read.insert(name); }
+                        None => {
+                            driven.insert(name);
+                            read.insert(name);
+                        }
+                    }
+                }
+            }
+        }
+        
+        let mut warnings: Vec<LintWarning> = hdl_chip.inputs.iter()
+            .filter(|input| !read.contains(input.name.as_str()))
+            .map(|input| LintWarning::UnusedInput(input.name.clone()))
+            .collect();
+        warnings.extend(hdl_chip.outputs.iter()
+            .filter(|output| !driven.contains(output.name.as_str()))
+            .map(|output| LintWarning::UnconnectedOutput(output.name.clone())));
+        
+        let declared = |name: &str| hdl_chip.inputs.iter().chain(&hdl_chip.outputs).any(|decl| decl.name == name);
+        let mut unread: Vec<&str> = driven.iter()
+            .filter(|name| !declared(name) && !read.contains(*name))
+            .copied()
+            .collect();
+        unread.sort();
+        warnings.extend(unread.into_iter().map(|name| LintWarning::UnreadInternal(name.to_string())));
+        
+        warnings
+    }
+    
+    /// Ensure a BUILTIN chip's HDL header declares exactly the builtin's IN/OUT pins and widths
+    fn check_builtin_header(&self, hdl_chip: &HdlChip, chip: &dyn ChipInterface) -> Result<()> {
+        let sections = [
+            ("IN", &hdl_chip.inputs, chip.input_pin_names_sorted()),
+            ("OUT", &hdl_chip.outputs, chip.output_pin_names_sorted()),
+        ];
+        
+        for (section, decls, actual) in sections {
+            for decl in decls {
+                if !actual.contains(&decl.name) {
+                    return Err(SimulatorError::Hardware(format!(
+                        "Builtin chip {} has no {} pin '{}' (builtin declares: {})",
+                        hdl_chip.name, section, decl.name, actual.join(", ")
+                    )));
                 }
                 
-                // Try to find in chip pins
-                chip.get_pin(name)
+                let declared_width = decl.width.unwrap_or(1) as usize;
+                let actual_width = chip.pin_width(&decl.name)?;
+                if declared_width != actual_width {
+                    return Err(SimulatorError::Hardware(format!(
+                        "Width mismatch for {} pin '{}' of builtin chip {}: HDL declares {} bits, builtin has {}",
+                        section, decl.name, hdl_chip.name, declared_width, actual_width
+                    )));
+                }
             }
-            WireSide::Constant(value) => {
-                let constant_name = if *value { "true" } else { "false" };
-                let constant = ConstantPin::new(constant_name.to_string())?;
-                Ok(Rc::new(RefCell::new(constant)))
+            
+            if let Some(missing) = actual.iter().find(|name| !decls.iter().any(|decl| &decl.name == *name)) {
+                return Err(SimulatorError::Hardware(format!(
+                    "HDL header of builtin chip {} is missing {} pin '{}'",
+                    hdl_chip.name, section, missing
+                )));
             }
         }
+        
+        Ok(())
+    }
+    
+    fn wire_to_connection(&self, wire: &Wire) -> Result<Connection> {
+        let from = match &wire.from {
+            WireSide::Pin { name, range: Some(range) } => PinSide::with_range(name.clone(), range.clone()),
+            WireSide::Pin { name, range: None } => PinSide::new(name.clone()),
+            WireSide::Constant { value, boolean: true, .. } => PinSide::boolean(*value != 0),
+            WireSide::Constant { value, width, boolean: false } => PinSide::constant(*value, *width),
+        };
+        
+        let to = match &wire.to {
+            WireSide::Pin { name, range: Some(range) } => PinSide::with_range(name.clone(), range.clone()),
+            WireSide::Pin { name, range: None } => PinSide::new(name.clone()),
+            WireSide::Constant { .. } => {
+                return Err(SimulatorError::Hardware(
+                    "A part pin cannot be a constant".to_string()
+                ));
+            }
+        };
+        
+        Ok(Connection::new(from, to))
+    }
+    
+    /// Synthesize a combinational chip from a truth table of packed (inputs, outputs) rows
+    /// Pins are 1-bit and packed in declaration order, the first pin being the most significant bit
+    pub fn from_truth_table(
+        name: &str,
+        inputs: &[&str],
+        outputs: &[&str],
+        rows: &[(u32, u32)],
+    ) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(TruthTableChip::new(name, inputs, outputs, rows)?))
     }
     
     pub fn build_builtin_chip(&self, name: &str) -> Result<Box<dyn ChipInterface>> {
-        if let Some(factory) = self.builtin_registry.get(name) {
-            Ok(factory())
+        let mut chip = if let Some(factory) = self.builtin_registry.get(name) {
+            factory()
+        } else if let Some(chip) = self.build_sized_chip(name)? {
+            chip
         } else {
-            Err(SimulatorError::Hardware(format!("Unknown builtin chip: {}", name)))
+            return Err(SimulatorError::Hardware(format!("Unknown builtin chip: {}", name)));
+        };
+        
+        if self.strict_floating && chip.as_clocked_mut().is_none() {
+            chip = Box::new(FloatingCheckChip::new(chip));
+        }
+        Ok(chip)
+    }
+    
+    /// Whether the builtin called `name` is sequential and needs clock edges
+    /// ROM32K and Keyboard are combinational: their contents change only from outside
+    pub fn is_clocked(name: &str) -> bool {
+        if CLOCKED_BUILTINS.contains(&name) {
+            return true;
+        }
+        let split = name.find(|c: char| c.is_ascii_digit()).unwrap_or(name.len());
+        let (base, digits) = name.split_at(split);
+        !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) && CLOCKED_SIZED_BUILTINS.contains(&base)
+    }
+    
+    /// Build chips whose width is part of the name, e.g. "Register8", "Decoder3" or "SignExtend8to16",
+    /// and the N-way muxes named by their way count, e.g. "Mux3Way" or "DMux5Way"
+    fn build_sized_chip(&self, name: &str) -> Result<Option<Box<dyn ChipInterface>>> {
+        if let Some((from, to)) = name.strip_prefix("SignExtend").and_then(|widths| widths.split_once("to")) {
+            return match (from.parse::<usize>(), to.parse::<usize>()) {
+                (Ok(in_width), Ok(out_width)) => Ok(Some(Box::new(SignExtendChip::new(in_width, out_width)?))),
+                _ => Ok(None),
+            };
+        }
+        
+        if let Some(ways) = name.strip_suffix("Way") {
+            if let Some(ways) = ways.strip_prefix("DMux").and_then(|ways| ways.parse::<usize>().ok()) {
+                return Ok(Some(Box::new(DMuxNWayChip::new(ways)?)));
+            }
+            if let Some(ways) = ways.strip_prefix("Mux").and_then(|ways| ways.parse::<usize>().ok()) {
+                return Ok(Some(Box::new(MuxNWayChip::new(ways)?)));
+            }
+        }
+        
+        let split = name.find(|c: char| c.is_ascii_digit()).unwrap_or(name.len());
+        let (base, digits) = name.split_at(split);
+        let width = match digits.parse::<usize>() {
+            Ok(width) => width,
+            Err(_) => return Ok(None),
+        };
+        
+        match base {
+            "Register" => Ok(Some(Box::new(RegisterNChip::new(width)?))),
+            "RegisterFile" => Ok(Some(Box::new(RegisterFileChip::new(width)?))),
+            "And" => Ok(Some(Box::new(BitwiseNChip::new(BitwiseOp::And, width)?))),
+            "Or" => Ok(Some(Box::new(BitwiseNChip::new(BitwiseOp::Or, width)?))),
+            "Not" => Ok(Some(Box::new(BitwiseNChip::new(BitwiseOp::Not, width)?))),
+            "Inc" => Ok(Some(Box::new(IncNChip::new(width)?))),
+            "Decoder" => Ok(Some(Box::new(DecoderChip::new(width)?))),
+            _ => Ok(None),
         }
     }
     
@@ -211,6 +565,66 @@ impl ChipBuilder {
             Box::new(Or16Chip::new())
         }));
         
+        self.builtin_registry.insert("OrReduce16".to_string(), Box::new(|| {
+            Box::new(OrReduceChip::new())
+        }));
+        
+        self.builtin_registry.insert("AndReduce16".to_string(), Box::new(|| {
+            Box::new(AndReduceChip::new())
+        }));
+        
+        self.builtin_registry.insert("Equal16".to_string(), Box::new(|| {
+            Box::new(Equal16Chip::new())
+        }));
+        
+        self.builtin_registry.insert("SGreater16".to_string(), Box::new(|| {
+            Box::new(GreaterThan16Chip::new(true))
+        }));
+        
+        self.builtin_registry.insert("UGreater16".to_string(), Box::new(|| {
+            Box::new(GreaterThan16Chip::new(false))
+        }));
+        
+        self.builtin_registry.insert("Comparator16".to_string(), Box::new(|| {
+            Box::new(Comparator16Chip::new(false))
+        }));
+        
+        self.builtin_registry.insert("SComparator16".to_string(), Box::new(|| {
+            Box::new(Comparator16Chip::new(true))
+        }));
+        
+        self.builtin_registry.insert("ShiftLeft16".to_string(), Box::new(|| {
+            Box::new(ShiftLeft16Chip::new())
+        }));
+        
+        self.builtin_registry.insert("ShiftRight16".to_string(), Box::new(|| {
+            Box::new(ShiftRight16Chip::new())
+        }));
+        
+        self.builtin_registry.insert("ShiftRightArith16".to_string(), Box::new(|| {
+            Box::new(ShiftRight16Chip::new_arithmetic())
+        }));
+        
+        self.builtin_registry.insert("Reverse16".to_string(), Box::new(|| {
+            Box::new(Reverse16Chip::new())
+        }));
+        
+        self.builtin_registry.insert("ByteSwap16".to_string(), Box::new(|| {
+            Box::new(ByteSwap16Chip::new())
+        }));
+        
+        self.builtin_registry.insert("PopCount16".to_string(), Box::new(|| {
+            Box::new(PopCount16Chip::new())
+        }));
+        
+        self.builtin_registry.insert("PriorityEncoder16".to_string(), Box::new(|| {
+            Box::new(PriorityEncoder16Chip::new())
+        }));
+        
+        self.builtin_registry.insert("TriState16".to_string(), Box::new(|| {
+            Box::new(TriStateBufferChip::new())
+        }));
+        
         self.builtin_registry.insert("Mux16".to_string(), Box::new(|| {
             Box::new(Mux16Chip::new())
         }));
@@ -239,6 +653,14 @@ impl ChipBuilder {
             Box::new(FullAdderChip::new())
         }));
         
+        self.builtin_registry.insert("HalfSubtractor".to_string(), Box::new(|| {
+            Box::new(HalfSubtractorChip::new())
+        }));
+        
+        self.builtin_registry.insert("FullSubtractor".to_string(), Box::new(|| {
+            Box::new(FullSubtractorChip::new())
+        }));
+        
         self.builtin_registry.insert("ALU".to_string(), Box::new(|| {
             Box::new(AluChip::new())
         }));
@@ -256,10 +678,18 @@ impl ChipBuilder {
             Box::new(RegisterChip::new())
         }));
         
+        self.builtin_registry.insert("EnabledRegister".to_string(), Box::new(|| {
+            Box::new(EnabledRegisterChip::new())
+        }));
+        
         self.builtin_registry.insert("PC".to_string(), Box::new(|| {
             Box::new(PcChip::new())
         }));
         
+        self.builtin_registry.insert("Counter".to_string(), Box::new(|| {
+            Box::new(CounterChip::new())
+        }));
+        
         self.builtin_registry.insert("RAM8".to_string(), Box::new(|| {
             Box::new(Ram8Chip::new())
         }));
@@ -268,6 +698,10 @@ impl ChipBuilder {
             Box::new(Ram64Chip::new())
         }));
         
+        self.builtin_registry.insert("SyncRAM64".to_string(), Box::new(|| {
+            Box::new(SyncRam64Chip::new())
+        }));
+        
         self.builtin_registry.insert("RAM512".to_string(), Box::new(|| {
             Box::new(Ram512Chip::new())
         }));
@@ -284,6 +718,10 @@ impl ChipBuilder {
             Box::new(Rom32kChip::new())
         }));
         
+        self.builtin_registry.insert("ProgRAM".to_string(), Box::new(|| {
+            Box::new(RamRomChip::new())
+        }));
+        
         self.builtin_registry.insert("Screen".to_string(), Box::new(|| {
             Box::new(ScreenChip::new())
         }));
@@ -291,6 +729,18 @@ impl ChipBuilder {
         self.builtin_registry.insert("Keyboard".to_string(), Box::new(|| {
             Box::new(KeyboardChip::new())
         }));
+        
+        self.builtin_registry.insert("Decode".to_string(), Box::new(|| {
+            Box::new(DecodeChip::new())
+        }));
+        
+        self.builtin_registry.insert("JumpLogic".to_string(), Box::new(|| {
+            Box::new(JumpChip::new())
+        }));
+        
+        self.builtin_registry.insert("Computer".to_string(), Box::new(|| {
+            Box::new(ComputerChip::new())
+        }));
     }
 }
 
@@ -307,6 +757,7 @@ impl Default for ChipBuilder {
 mod tests {
     use super::*;
     use crate::chip::pin::{HIGH, LOW};
+    use crate::test::truth_table;
     use crate::languages::hdl::{HdlParser};
     
     #[test]
@@ -402,6 +853,31 @@ mod tests {
         assert!(chip.output_pins().contains_key("out"));
     }
     
+    #[test]
+    fn test_strict_floating_rejects_undriven_inputs() {
+        // Only `a` is ever driven
+        let mut lenient = ChipBuilder::new().build_builtin_chip("And").unwrap();
+        lenient.set_pin("a", 1).unwrap();
+        lenient.eval().unwrap();
+        assert_eq!(lenient.read_pin("out").unwrap(), 0);
+        
+        let mut strict = ChipBuilder::new().strict_floating(true).build_builtin_chip("And").unwrap();
+        strict.set_pin("a", 1).unwrap();
+        match strict.eval() {
+            Err(SimulatorError::FloatingInput { pin, chip }) => assert_eq!((pin.as_str(), chip.as_str()), ("b", "And")),
+            other => panic!("expected FloatingInput, got {:?}", other),
+        }
+        
+        strict.set_pin("b", 1).unwrap();
+        strict.eval().unwrap();
+        assert_eq!(strict.read_pin("out").unwrap(), 1);
+        
+        // Clocked builtins are not wrapped
+        let mut dff = ChipBuilder::new().strict_floating(true).build_builtin_chip("DFF").unwrap();
+        assert!(dff.as_clocked_mut().is_some());
+        dff.eval().unwrap();
+    }
+    
     #[test]
     fn test_builtin_or_chip() {
         let builder = ChipBuilder::new();
@@ -516,6 +992,425 @@ mod tests {
         assert_eq!(output, 0x1234 | 0x5678, "OR16(0x1234, 0x5678) should be correct");
     }
     
+    #[test]
+    fn test_builtin_or_reduce16_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("OrReduce16").unwrap();
+        
+        for (input, expected) in [(0x0000, LOW), (0x0001, HIGH), (0x8000, HIGH), (0xffff, HIGH)] {
+            chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(input);
+            chip.eval().unwrap();
+            let output = chip.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+            assert_eq!(output, expected, "OrReduce16({:#06x}) should be {}", input, expected);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_and_reduce16_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("AndReduce16").unwrap();
+        
+        for (input, expected) in [(0xffff, HIGH), (0xfffe, LOW), (0x7fff, LOW), (0x0000, LOW)] {
+            chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(input);
+            chip.eval().unwrap();
+            let output = chip.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+            assert_eq!(output, expected, "AndReduce16({:#06x}) should be {}", input, expected);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_equal16_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("Equal16").unwrap();
+        
+        for (a, b, expected) in [(0x1234, 0x1234, HIGH), (0x1234, 0x1235, LOW), (0x0000, 0x0000, HIGH), (0xffff, 0x7fff, LOW)] {
+            chip.get_pin("a").unwrap().borrow_mut().set_bus_voltage(a);
+            chip.get_pin("b").unwrap().borrow_mut().set_bus_voltage(b);
+            chip.eval().unwrap();
+            let output = chip.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+            assert_eq!(output, expected, "Equal16({:#06x}, {:#06x}) should be {}", a, b, expected);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_greater16_chips() {
+        let builder = ChipBuilder::new();
+        let mut unsigned = builder.build_builtin_chip("UGreater16").unwrap();
+        let mut signed = builder.build_builtin_chip("SGreater16").unwrap();
+        
+        // (a, b, unsigned a > b, signed a > b)
+        let cases = [
+            (0xffff, 0x0001, HIGH, LOW),  // 65535 > 1, but -1 < 1
+            (0x0001, 0xffff, LOW, HIGH),
+            (0x7fff, 0x8000, LOW, HIGH),  // 32767 vs 32768 / -32768
+            (0x0005, 0x0005, LOW, LOW),
+            (0x0006, 0x0005, HIGH, HIGH),
+        ];
+        for (a, b, expected_unsigned, expected_signed) in cases {
+            for chip in [&mut unsigned, &mut signed] {
+                chip.get_pin("a").unwrap().borrow_mut().set_bus_voltage(a);
+                chip.get_pin("b").unwrap().borrow_mut().set_bus_voltage(b);
+                chip.eval().unwrap();
+            }
+            let output = unsigned.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+            assert_eq!(output, expected_unsigned, "UGreater16({:#06x}, {:#06x})", a, b);
+            let output = signed.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+            assert_eq!(output, expected_signed, "SGreater16({:#06x}, {:#06x})", a, b);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_comparator16_chips() {
+        let builder = ChipBuilder::new();
+        let mut unsigned = builder.build_builtin_chip("Comparator16").unwrap();
+        let mut signed = builder.build_builtin_chip("SComparator16").unwrap();
+        
+        // (a, b, unsigned [lt, eq, gt], signed [lt, eq, gt])
+        let cases = [
+            (0x0001, 0x0002, [HIGH, LOW, LOW], [HIGH, LOW, LOW]),
+            (0x1234, 0x1234, [LOW, HIGH, LOW], [LOW, HIGH, LOW]),
+            (0x0003, 0x0002, [LOW, LOW, HIGH], [LOW, LOW, HIGH]),
+            (0xffff, 0x0001, [LOW, LOW, HIGH], [HIGH, LOW, LOW]),  // 65535 > 1, but -1 < 1
+            (0x7fff, 0x8000, [HIGH, LOW, LOW], [LOW, LOW, HIGH]),
+        ];
+        for (a, b, expected_unsigned, expected_signed) in cases {
+            for (chip, expected) in [(&mut unsigned, expected_unsigned), (&mut signed, expected_signed)] {
+                chip.get_pin("a").unwrap().borrow_mut().set_bus_voltage(a);
+                chip.get_pin("b").unwrap().borrow_mut().set_bus_voltage(b);
+                chip.eval().unwrap();
+                let outputs = ["lt", "eq", "gt"].map(|pin| chip.get_pin(pin).unwrap().borrow().voltage(None).unwrap());
+                assert_eq!(outputs, expected, "{}({:#06x}, {:#06x})", chip.name(), a, b);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_builtin_shift16_chips() {
+        let builder = ChipBuilder::new();
+        
+        // (chip, in, shamt, expected)
+        let cases = [
+            ("ShiftLeft16", 0x0001, 4, 0x0010),
+            ("ShiftLeft16", 0x8001, 1, 0x0002),
+            ("ShiftLeft16", 0x1234, 0, 0x1234),
+            ("ShiftRight16", 0x8000, 1, 0x4000),
+            ("ShiftRight16", 0x00f0, 4, 0x000f),
+            ("ShiftRightArith16", 0x8000, 1, 0xc000),
+            ("ShiftRightArith16", 0x8000, 15, 0xffff),
+            ("ShiftRightArith16", 0x4000, 1, 0x2000),
+        ];
+        for (name, input, shamt, expected) in cases {
+            let mut chip = builder.build_builtin_chip(name).unwrap();
+            chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(input);
+            chip.get_pin("shamt").unwrap().borrow_mut().set_bus_voltage(shamt);
+            chip.eval().unwrap();
+            let output = chip.get_pin("out").unwrap().borrow().bus_voltage();
+            assert_eq!(output, expected, "{}({:#06x}, {}) should be {:#06x}", name, input, shamt, expected);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_bit_reorder_chips() {
+        let builder = ChipBuilder::new();
+        
+        // (chip, in, expected)
+        let cases = [
+            ("Reverse16", 0x0001, 0x8000),
+            ("Reverse16", 0x8000, 0x0001),
+            ("Reverse16", 0x00f0, 0x0f00),
+            ("Reverse16", 0xa5a5, 0xa5a5),
+            ("ByteSwap16", 0x1234, 0x3412),
+            ("ByteSwap16", 0x00ff, 0xff00),
+            ("ByteSwap16", 0xabab, 0xabab),
+        ];
+        for (name, input, expected) in cases {
+            let mut chip = builder.build_builtin_chip(name).unwrap();
+            chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(input);
+            chip.eval().unwrap();
+            let output = chip.get_pin("out").unwrap().borrow().bus_voltage();
+            assert_eq!(output, expected, "{}({:#06x}) should be {:#06x}", name, input, expected);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_pop_count16_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("PopCount16").unwrap();
+        assert_eq!(chip.get_pin("out").unwrap().borrow().width(), 5);
+        
+        for (input, expected) in [(0x0000, 0), (0xffff, 16), (0x000f, 4), (0xaaaa, 8), (0x8001, 2)] {
+            chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(input);
+            chip.eval().unwrap();
+            let output = chip.get_pin("out").unwrap().borrow().bus_voltage();
+            assert_eq!(output, expected, "PopCount16({:#06x}) should be {}", input, expected);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_priority_encoder16_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("PriorityEncoder16").unwrap();
+        assert_eq!(chip.get_pin("out").unwrap().borrow().width(), 4);
+        
+        for (input, expected, valid) in [(0x0001, 0, HIGH), (0x8000, 15, HIGH), (0x0006, 2, HIGH), (0xffff, 15, HIGH), (0x0000, 0, LOW)] {
+            chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(input);
+            chip.eval().unwrap();
+            let output = chip.get_pin("out").unwrap().borrow().bus_voltage();
+            assert_eq!(output, expected, "PriorityEncoder16({:#06x}) should be {}", input, expected);
+            assert_eq!(chip.get_pin("valid").unwrap().borrow().voltage(None).unwrap(), valid, "valid for {:#06x}", input);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_decoder_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("Decoder3").unwrap();
+        assert_eq!(chip.get_pin("in").unwrap().borrow().width(), 3);
+        assert_eq!(chip.get_pin("out").unwrap().borrow().width(), 8);
+        
+        for input in 0..8u16 {
+            chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(input);
+            chip.eval().unwrap();
+            let output = chip.get_pin("out").unwrap().borrow().bus_voltage();
+            assert_eq!(output, 1 << input, "Decoder3({}) should set only bit {}", input, input);
+        }
+        
+        assert!(builder.build_builtin_chip("Decoder5").is_err());
+    }
+    
+    #[test]
+    fn test_builtin_tristate16_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("TriState16").unwrap();
+        
+        chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0xbeef);
+        chip.get_pin("enable").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+        chip.eval().unwrap();
+        let out = chip.get_pin("out").unwrap();
+        assert!(!out.borrow().is_floating());
+        assert_eq!(out.borrow().bus_voltage(), 0xbeef);
+        
+        // Disabled: the buffer releases the bus
+        chip.get_pin("enable").unwrap().borrow_mut().pull(LOW, None).unwrap();
+        chip.eval().unwrap();
+        assert!(out.borrow().is_floating());
+        assert_eq!(out.borrow().bus_voltage(), 0, "Floating bus reads as LOW");
+        
+        chip.get_pin("enable").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+        chip.eval().unwrap();
+        assert!(!out.borrow().is_floating());
+        assert_eq!(out.borrow().bus_voltage(), 0xbeef);
+    }
+    
+    #[test]
+    fn test_is_clocked_matches_builtins() {
+        assert!(ChipBuilder::is_clocked("DFF"));
+        assert!(ChipBuilder::is_clocked("RAM16K"));
+        assert!(ChipBuilder::is_clocked("Register8"));
+        assert!(!ChipBuilder::is_clocked("And"));
+        assert!(!ChipBuilder::is_clocked("And4"));
+        assert!(!ChipBuilder::is_clocked("ROM32K"));
+        assert!(!ChipBuilder::is_clocked("Nonexistent"));
+        
+        // The static set agrees with which builtins actually implement ClockedChip
+        let builder = ChipBuilder::new();
+        for name in builder.builtin_registry.keys() {
+            let mut chip = builder.build_builtin_chip(name).unwrap();
+            assert_eq!(ChipBuilder::is_clocked(name), chip.as_clocked_mut().is_some(), "{}", name);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_decode_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("Decode").unwrap();
+        
+        // AM=M-1;JGE: 111 a=1 comp=110010 dest=101 jump=011
+        chip.set_pin("instruction", 0b1111_1100_1010_1011).unwrap();
+        chip.eval().unwrap();
+        assert_eq!(chip.read_pin("isA").unwrap(), 0);
+        assert_eq!(chip.read_pin("isC").unwrap(), 1);
+        assert_eq!(chip.read_pin("aBit").unwrap(), 1);
+        assert_eq!(chip.read_pin("comp").unwrap(), 0b1_110010);
+        assert_eq!(chip.read_pin("dest").unwrap(), 0b101);
+        assert_eq!(chip.read_pin("jump").unwrap(), 0b011);
+        
+        // @21845 sets only isA; the fields of an A-instruction read 0
+        chip.set_pin("instruction", 0x5555).unwrap();
+        chip.eval().unwrap();
+        assert_eq!(chip.read_pin("isA").unwrap(), 1);
+        assert_eq!(chip.read_pin("isC").unwrap(), 0);
+        for field in ["aBit", "comp", "dest", "jump"] {
+            assert_eq!(chip.read_pin(field).unwrap(), 0, "{} of an A-instruction", field);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_jump_logic_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("JumpLogic").unwrap();
+        
+        // (zr, ng) for a positive, zero and negative ALU result
+        let flags = [(0, 0), (1, 0), (0, 1)];
+        // jump bits -> whether each of those results jumps
+        let cases = [
+            (0b000, [false, false, false]), // null
+            (0b001, [true, false, false]),  // JGT
+            (0b010, [false, true, false]),  // JEQ
+            (0b011, [true, true, false]),   // JGE
+            (0b100, [false, false, true]),  // JLT
+            (0b101, [true, false, true]),   // JNE
+            (0b110, [false, true, true]),   // JLE
+            (0b111, [true, true, true]),    // JMP
+        ];
+        for (jump, expected) in cases {
+            for ((zr, ng), expected) in flags.into_iter().zip(expected) {
+                chip.set_pin("jump", jump).unwrap();
+                chip.set_pin("zr", zr).unwrap();
+                chip.set_pin("ng", ng).unwrap();
+                chip.eval().unwrap();
+                assert_eq!(chip.read_pin("out").unwrap(), expected as u16, "jump={:03b} zr={} ng={}", jump, zr, ng);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_builtin_sized_inc_chips() {
+        let builder = ChipBuilder::new();
+        
+        // (chip, in, expected)
+        let cases = [
+            ("Inc4", 0b1111, 0b0000),
+            ("Inc4", 0b0111, 0b1000),
+            ("Inc8", 0x7f, 0x80),
+            ("Inc8", 0xff, 0x00),
+            ("Inc1", 1, 0),
+            ("Inc16", 0xffff, 0x0000),
+        ];
+        for (name, input, expected) in cases {
+            let mut chip = builder.build_builtin_chip(name).unwrap();
+            assert_eq!(chip.name(), name);
+            chip.set_pin("in", input).unwrap();
+            chip.eval().unwrap();
+            assert_eq!(chip.read_pin("out").unwrap(), expected, "{}({:#x})", name, input);
+        }
+        
+        assert!(builder.build_builtin_chip("Inc0").is_err());
+        assert!(builder.build_builtin_chip("Inc17").is_err());
+    }
+    
+    #[test]
+    fn test_builtin_sign_extend_chip() {
+        let builder = ChipBuilder::new();
+        
+        let mut chip = builder.build_builtin_chip("SignExtend8to16").unwrap();
+        assert_eq!(chip.name(), "SignExtend8to16");
+        assert_eq!(chip.pin_width("in").unwrap(), 8);
+        assert_eq!(chip.pin_width("out").unwrap(), 16);
+        for (input, expected) in [(0xff, 0xffff), (0x7f, 0x007f), (0x80, 0xff80), (0x00, 0x0000)] {
+            chip.set_pin("in", input).unwrap();
+            chip.eval().unwrap();
+            assert_eq!(chip.read_pin("out").unwrap(), expected, "SignExtend8to16({:#04x})", input);
+        }
+        
+        // Narrow outputs keep only their own width
+        let mut chip = builder.build_builtin_chip("SignExtend4to6").unwrap();
+        chip.set_pin("in", 0b1010).unwrap();
+        chip.eval().unwrap();
+        assert_eq!(chip.read_pin("out").unwrap(), 0b111010);
+        
+        assert!(builder.build_builtin_chip("SignExtend16to8").is_err());
+        assert!(builder.build_builtin_chip("SignExtend8to").is_err());
+    }
+    
+    #[test]
+    fn test_builtin_sized_bitwise_chips() {
+        let builder = ChipBuilder::new();
+        
+        let mut and4 = builder.build_builtin_chip("And4").unwrap();
+        assert_eq!(and4.name(), "And4");
+        and4.set_pin("a", 0b1010).unwrap();
+        and4.set_pin("b", 0b1100).unwrap();
+        and4.eval().unwrap();
+        assert_eq!(and4.read_pin("out").unwrap(), 0b1000);
+        
+        let mut or8 = builder.build_builtin_chip("Or8").unwrap();
+        or8.set_pin("a", 0x0f).unwrap();
+        or8.set_pin("b", 0xf0).unwrap();
+        or8.eval().unwrap();
+        assert_eq!(or8.read_pin("out").unwrap(), 0xff);
+        
+        // Not inverts within its width only
+        let mut not3 = builder.build_builtin_chip("Not3").unwrap();
+        not3.set_pin("in", 0b010).unwrap();
+        not3.eval().unwrap();
+        assert_eq!(not3.read_pin("out").unwrap(), 0b101);
+        
+        // Fixed-width registrations still win, and widths past the bus limit are rejected
+        assert_eq!(builder.build_builtin_chip("And16").unwrap().name(), "And16");
+        assert!(builder.build_builtin_chip("Not32").is_err());
+        assert!(builder.build_builtin_chip("And0").is_err());
+    }
+    
+    #[test]
+    fn test_from_truth_table_matches_builtin_xor() {
+        let builder = ChipBuilder::new();
+        let mut xor_builtin = builder.build_builtin_chip("Xor").unwrap();
+        let mut xor_table = ChipBuilder::from_truth_table(
+            "TableXor",
+            &["a", "b"],
+            &["out"],
+            &[(0b00, 0), (0b01, 1), (0b10, 1), (0b11, 0)],
+        ).unwrap();
+        
+        for (a, b) in [(LOW, LOW), (LOW, HIGH), (HIGH, LOW), (HIGH, HIGH)] {
+            for chip in [&mut xor_builtin, &mut xor_table] {
+                chip.get_pin("a").unwrap().borrow_mut().pull(a, None).unwrap();
+                chip.get_pin("b").unwrap().borrow_mut().pull(b, None).unwrap();
+                chip.eval().unwrap();
+            }
+            let expected = xor_builtin.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+            let actual = xor_table.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+            assert_eq!(actual, expected, "Table XOR({}, {}) should match builtin", a, b);
+        }
+    }
+    
+    #[test]
+    fn test_from_truth_table_xor_table_matches_builtin() {
+        let builder = ChipBuilder::new();
+        let mut xor_builtin = builder.build_builtin_chip("Xor").unwrap();
+        let mut xor_table = ChipBuilder::from_truth_table(
+            "TableXor",
+            &["a", "b"],
+            &["out"],
+            &[(0b00, 0), (0b01, 1), (0b10, 1), (0b11, 0)],
+        ).unwrap();
+        
+        let expected = truth_table(xor_builtin.as_mut(), &["a", "b"], &["out"]);
+        assert_eq!(truth_table(xor_table.as_mut(), &["a", "b"], &["out"]), expected);
+        assert_eq!(expected.len(), 4);
+    }
+    
+    #[test]
+    fn test_from_truth_table_multiple_outputs() {
+        // Half adder: outputs packed as (sum, carry)
+        let mut half_adder = ChipBuilder::from_truth_table(
+            "TableHalfAdder",
+            &["a", "b"],
+            &["sum", "carry"],
+            &[(0b00, 0b00), (0b01, 0b10), (0b10, 0b10), (0b11, 0b01)],
+        ).unwrap();
+        
+        half_adder.get_pin("a").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+        half_adder.get_pin("b").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+        half_adder.eval().unwrap();
+        assert_eq!(half_adder.get_pin("sum").unwrap().borrow().voltage(None).unwrap(), LOW);
+        assert_eq!(half_adder.get_pin("carry").unwrap().borrow().voltage(None).unwrap(), HIGH);
+        
+        // Rows that don't fit the declared pins are rejected
+        assert!(ChipBuilder::from_truth_table("Bad", &["a"], &["out"], &[(0b10, 0)]).is_err());
+    }
+    
     #[test]
     fn test_bus_voltage_operations() {
         // Test from TypeScript: "sets and returns wide busses"
@@ -585,6 +1480,54 @@ mod tests {
         }
     }
     
+    #[test]
+    fn test_builtin_dmux_n_way_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("DMux3Way").unwrap();
+        assert_eq!(chip.pin_width("sel").unwrap(), 2);
+        assert_eq!(chip.output_pin_names_sorted(), vec!["a", "b", "c"]);
+        
+        chip.set_pin("in", 1).unwrap();
+        for (sel, expected) in [(0, [1, 0, 0]), (1, [0, 1, 0]), (2, [0, 0, 1]), (3, [0, 0, 0])] {
+            chip.set_pin("sel", sel).unwrap();
+            chip.eval().unwrap();
+            let outputs = ["a", "b", "c"].map(|pin| chip.read_pin(pin).unwrap());
+            assert_eq!(outputs, expected, "DMux3Way(in=1, sel={})", sel);
+        }
+        
+        // The fixed-size chips route the same way
+        let mut fixed = builder.build_builtin_chip("DMux4Way").unwrap();
+        let mut general: Box<dyn ChipInterface> = Box::new(DMuxNWayChip::new(4).unwrap());
+        for sel in 0..4 {
+            for chip in [&mut fixed, &mut general] {
+                chip.set_pin("in", 1).unwrap();
+                chip.set_pin("sel", sel).unwrap();
+                chip.eval().unwrap();
+            }
+            for pin in ["a", "b", "c", "d"] {
+                assert_eq!(fixed.read_pin(pin).unwrap(), general.read_pin(pin).unwrap());
+            }
+        }
+        
+        assert!(builder.build_builtin_chip("DMux1Way").is_err());
+        assert!(builder.build_builtin_chip("DMux17Way").is_err());
+    }
+    
+    #[test]
+    fn test_builtin_mux_n_way_chip() {
+        let builder = ChipBuilder::new();
+        let mut chip = builder.build_builtin_chip("Mux5Way").unwrap();
+        assert_eq!(chip.pin_width("sel").unwrap(), 3);
+        
+        // Only e is high
+        chip.set_pin("e", 1).unwrap();
+        for (sel, expected) in [(0, 0), (3, 0), (4, 1), (5, 0), (7, 0)] {
+            chip.set_pin("sel", sel).unwrap();
+            chip.eval().unwrap();
+            assert_eq!(chip.read_pin("out").unwrap(), expected, "Mux5Way(sel={})", sel);
+        }
+    }
+    
     #[test]
     fn test_builtin_mux16_chip() {
         let builder = ChipBuilder::new();
@@ -737,6 +1680,61 @@ mod tests {
         }
     }
     
+    #[test]
+    fn test_builtin_half_subtractor_chip() {
+        let builder = ChipBuilder::new();
+        let mut half_subtractor_chip = builder.build_builtin_chip("HalfSubtractor").unwrap();
+        
+        // Test half subtractor truth table (a - b)
+        let test_cases = [
+            (LOW, LOW, LOW, LOW),     // 0 - 0 = diff:0, borrow:0
+            (LOW, HIGH, HIGH, HIGH),  // 0 - 1 = diff:1, borrow:1
+            (HIGH, LOW, HIGH, LOW),   // 1 - 0 = diff:1, borrow:0
+            (HIGH, HIGH, LOW, LOW),   // 1 - 1 = diff:0, borrow:0
+        ];
+        
+        for (a_val, b_val, expected_diff, expected_borrow) in test_cases {
+            half_subtractor_chip.get_pin("a").unwrap().borrow_mut().pull(a_val, None).unwrap();
+            half_subtractor_chip.get_pin("b").unwrap().borrow_mut().pull(b_val, None).unwrap();
+            half_subtractor_chip.eval().unwrap();
+            
+            let diff = half_subtractor_chip.get_pin("diff").unwrap().borrow().voltage(None).unwrap();
+            let borrow = half_subtractor_chip.get_pin("borrow").unwrap().borrow().voltage(None).unwrap();
+            assert_eq!(diff, expected_diff, "HalfSubtractor({}, {}) diff should be {}", a_val, b_val, expected_diff);
+            assert_eq!(borrow, expected_borrow, "HalfSubtractor({}, {}) borrow should be {}", a_val, b_val, expected_borrow);
+        }
+    }
+    
+    #[test]
+    fn test_builtin_full_subtractor_chip() {
+        let builder = ChipBuilder::new();
+        let mut full_subtractor_chip = builder.build_builtin_chip("FullSubtractor").unwrap();
+        
+        // Test full subtractor truth table (a - b - bin)
+        let test_cases = [
+            (LOW, LOW, LOW, LOW, LOW),       // 0 - 0 - 0 = diff:0, bout:0
+            (LOW, LOW, HIGH, HIGH, HIGH),    // 0 - 0 - 1 = diff:1, bout:1
+            (LOW, HIGH, LOW, HIGH, HIGH),    // 0 - 1 - 0 = diff:1, bout:1
+            (LOW, HIGH, HIGH, LOW, HIGH),    // 0 - 1 - 1 = diff:0, bout:1
+            (HIGH, LOW, LOW, HIGH, LOW),     // 1 - 0 - 0 = diff:1, bout:0
+            (HIGH, LOW, HIGH, LOW, LOW),     // 1 - 0 - 1 = diff:0, bout:0
+            (HIGH, HIGH, LOW, LOW, LOW),     // 1 - 1 - 0 = diff:0, bout:0
+            (HIGH, HIGH, HIGH, HIGH, HIGH),  // 1 - 1 - 1 = diff:1, bout:1
+        ];
+        
+        for (a_val, b_val, bin_val, expected_diff, expected_bout) in test_cases {
+            full_subtractor_chip.get_pin("a").unwrap().borrow_mut().pull(a_val, None).unwrap();
+            full_subtractor_chip.get_pin("b").unwrap().borrow_mut().pull(b_val, None).unwrap();
+            full_subtractor_chip.get_pin("bin").unwrap().borrow_mut().pull(bin_val, None).unwrap();
+            full_subtractor_chip.eval().unwrap();
+            
+            let diff = full_subtractor_chip.get_pin("diff").unwrap().borrow().voltage(None).unwrap();
+            let bout = full_subtractor_chip.get_pin("bout").unwrap().borrow().voltage(None).unwrap();
+            assert_eq!(diff, expected_diff, "FullSubtractor({}, {}, {}) diff should be {}", a_val, b_val, bin_val, expected_diff);
+            assert_eq!(bout, expected_bout, "FullSubtractor({}, {}, {}) bout should be {}", a_val, b_val, bin_val, expected_bout);
+        }
+    }
+    
     #[test]
     fn test_builtin_alu_chip() {
         let builder = ChipBuilder::new();
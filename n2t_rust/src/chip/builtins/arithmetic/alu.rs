@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
 use crate::chip::{ChipInterface, Bus, Pin};
-use crate::chip::pin::{HIGH, LOW};
+use crate::chip::pin::{Voltage, HIGH};
 use crate::error::Result;
 use super::super::{basic_chip_struct, impl_chip_interface_boilerplate};
 
@@ -14,6 +14,93 @@ pub enum AluFlags {
     Negative = 0x0f,
 }
 
+/// The six ALU control signals as one value, e.g. `AluControl::SUB` for x-y
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AluControl {
+    pub zx: bool,
+    pub nx: bool,
+    pub zy: bool,
+    pub ny: bool,
+    pub f: bool,
+    pub no: bool,
+}
+
+impl AluControl {
+    pub const ZERO: Self = Self::from_op(0b101010);
+    pub const ONE: Self = Self::from_op(0b111111);
+    pub const NEG_ONE: Self = Self::from_op(0b111010);
+    pub const X: Self = Self::from_op(0b001100);
+    pub const Y: Self = Self::from_op(0b110000);
+    pub const NOT_X: Self = Self::from_op(0b001101);
+    pub const NOT_Y: Self = Self::from_op(0b110001);
+    pub const NEG_X: Self = Self::from_op(0b001111);
+    pub const NEG_Y: Self = Self::from_op(0b110011);
+    pub const X_PLUS_1: Self = Self::from_op(0b011111);
+    pub const Y_PLUS_1: Self = Self::from_op(0b110111);
+    pub const X_MINUS_1: Self = Self::from_op(0b001110);
+    pub const Y_MINUS_1: Self = Self::from_op(0b110010);
+    pub const ADD: Self = Self::from_op(0b000010);
+    pub const SUB: Self = Self::from_op(0b010011);
+    pub const REV_SUB: Self = Self::from_op(0b000111);
+    pub const AND: Self = Self::from_op(0b000000);
+    pub const OR: Self = Self::from_op(0b010101);
+    
+    /// Decode a 6-bit control word, zx in bit 5 down to no in bit 0
+    pub const fn from_op(op: u16) -> Self {
+        Self {
+            zx: op & 0b100000 != 0,
+            nx: op & 0b010000 != 0,
+            zy: op & 0b001000 != 0,
+            ny: op & 0b000100 != 0,
+            f: op & 0b000010 != 0,
+            no: op & 0b000001 != 0,
+        }
+    }
+    
+    /// The 6-bit control word, as found in bits 11..6 of a C-instruction
+    pub fn op(&self) -> u16 {
+        ((self.zx as u16) << 5)
+            | ((self.nx as u16) << 4)
+            | ((self.zy as u16) << 3)
+            | ((self.ny as u16) << 2)
+            | ((self.f as u16) << 1)
+            | (self.no as u16)
+    }
+}
+
+/// The Hack ALU as a pure function: returns `out` and the flag it raises
+/// `AluChip` and the Computer's CPU both evaluate through this
+pub fn compute(control: AluControl, mut x: u16, mut y: u16) -> (u16, AluFlags) {
+    // Apply control signals to inputs
+    if control.zx { x = 0; }  // zero x
+    if control.nx { x = !x; } // negate x
+    if control.zy { y = 0; }  // zero y
+    if control.ny { y = !y; } // negate y
+    
+    // Compute operation: f=1 means addition, f=0 means AND
+    let mut result = if control.f {
+        x.wrapping_add(y)  // Addition with overflow handling
+    } else {
+        x & y  // Bitwise AND
+    };
+    
+    // Apply output negation if no=1
+    if control.no {
+        result = !result;
+    }
+    
+    // Determine flags
+    let flags = if result == 0 {
+        AluFlags::Zero
+    } else if result & 0x8000 != 0 {  // Check sign bit (bit 15)
+        AluFlags::Negative
+    } else {
+        AluFlags::Positive
+    };
+    
+    (result, flags)
+}
+
 basic_chip_struct!(AluChip);
 
 impl AluChip {
@@ -60,36 +147,25 @@ impl AluChip {
         chip
     }
     
-    // ALU implementation following the alua function from TypeScript
-    fn alu_operation(op: u16, mut x: u16, mut y: u16) -> (u16, AluFlags) {
-        // Apply control signals to inputs
-        if op & 0b100000 != 0 { x = 0; }           // zx: zero x
-        if op & 0b010000 != 0 { x = !x & 0xffff; } // nx: negate x
-        if op & 0b001000 != 0 { y = 0; }           // zy: zero y
-        if op & 0b000100 != 0 { y = !y & 0xffff; } // ny: negate y
-        
-        // Compute operation: f=1 means addition, f=0 means AND
-        let mut result = if op & 0b000010 != 0 {
-            x.wrapping_add(y) & 0xffff  // Addition with overflow handling
-        } else {
-            x & y  // Bitwise AND
-        };
-        
-        // Apply output negation if no=1
-        if op & 0b000001 != 0 {
-            result = !result & 0xffff;
+    /// Drive all six control pins in one call
+    pub fn set_control(&mut self, ctrl: AluControl) -> Result<()> {
+        let signals = [
+            ("zx", ctrl.zx),
+            ("nx", ctrl.nx),
+            ("zy", ctrl.zy),
+            ("ny", ctrl.ny),
+            ("f", ctrl.f),
+            ("no", ctrl.no),
+        ];
+        for (pin, value) in signals {
+            self.input_pins[pin].borrow_mut().pull(Voltage::from(value), None)?;
         }
-        
-        // Determine flags
-        let flags = if result == 0 {
-            AluFlags::Zero
-        } else if result & 0x8000 != 0 {  // Check sign bit (bit 15)
-            AluFlags::Negative
-        } else {
-            AluFlags::Positive
-        };
-        
-        (result, flags)
+        Ok(())
+    }
+    
+    // ALU implementation following the alua function from TypeScript
+    pub(crate) fn alu_operation(op: u16, x: u16, y: u16) -> (u16, AluFlags) {
+        compute(AluControl::from_op(op), x, y)
     }
 }
 
@@ -119,8 +195,8 @@ impl ChipInterface for AluChip {
         self.output_pins["out"].borrow_mut().set_bus_voltage(result);
         
         // Set flag outputs
-        let zr_out = if flags == AluFlags::Zero { HIGH } else { LOW };
-        let ng_out = if flags == AluFlags::Negative { HIGH } else { LOW };
+        let zr_out = Voltage::from(flags == AluFlags::Zero);
+        let ng_out = Voltage::from(flags == AluFlags::Negative);
         
         self.output_pins["zr"].borrow_mut().pull(zr_out, None)?;
         self.output_pins["ng"].borrow_mut().pull(ng_out, None)?;
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::pin::Voltage;
+use crate::error::Result;
+
+// Reverse16 - out[i] = in[15 - i]
+basic_chip_struct!(Reverse16Chip);
+
+impl Reverse16Chip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "Reverse16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 16)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for Reverse16Chip {
+    impl_chip_interface_boilerplate!("REVERSE16");
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(input.reverse_bits());
+        
+        Ok(())
+    }
+}
+
+// ByteSwap16 - exchanges the high and low bytes
+basic_chip_struct!(ByteSwap16Chip);
+
+impl ByteSwap16Chip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "ByteSwap16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 16)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for ByteSwap16Chip {
+    impl_chip_interface_boilerplate!("BYTE_SWAP16");
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(input.swap_bytes());
+        
+        Ok(())
+    }
+}
+
+// PopCount16 - out = number of set bits in `in` (0..=16, hence 5 bits)
+basic_chip_struct!(PopCount16Chip);
+
+impl PopCount16Chip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "PopCount16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 5)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for PopCount16Chip {
+    impl_chip_interface_boilerplate!("POP_COUNT16");
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(input.count_ones() as u16);
+        
+        Ok(())
+    }
+}
+
+// PriorityEncoder16 - out = index of the highest set bit of `in`, valid = any bit set
+// (out is 0 when nothing is set, so check valid first)
+basic_chip_struct!(PriorityEncoder16Chip);
+
+impl PriorityEncoder16Chip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "PriorityEncoder16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 4)));
+        let valid_pin = Rc::new(RefCell::new(Bus::new("valid".to_string(), 1)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        chip.output_pins.insert("valid".to_string(), valid_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for PriorityEncoder16Chip {
+    impl_chip_interface_boilerplate!("PRIORITY_ENCODER16");
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        let index = input.checked_ilog2().unwrap_or(0) as u16;
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(index);
+        self.output_pins["valid"].borrow_mut().pull(Voltage::from(input != 0), None)?;
+        
+        Ok(())
+    }
+}
+
+impl Default for Reverse16Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for ByteSwap16Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for PopCount16Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for PriorityEncoder16Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::pin::Voltage;
+use crate::error::Result;
+
+// Order two 16-bit words, as two's complement when signed
+fn compare(a: u16, b: u16, signed: bool) -> Ordering {
+    if signed { (a as i16).cmp(&(b as i16)) } else { a.cmp(&b) }
+}
+
+basic_chip_struct!(Equal16Chip);
+
+impl Equal16Chip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "Equal16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let a_pin = Rc::new(RefCell::new(Bus::new("a".to_string(), 16)));
+        let b_pin = Rc::new(RefCell::new(Bus::new("b".to_string(), 16)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 1)));
+        
+        chip.input_pins.insert("a".to_string(), a_pin);
+        chip.input_pins.insert("b".to_string(), b_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for Equal16Chip {
+    impl_chip_interface_boilerplate!("EQUAL16");
+
+    fn eval(&mut self) -> Result<()> {
+        let a = self.input_pins["a"].borrow().bus_voltage();
+        let b = self.input_pins["b"].borrow().bus_voltage();
+        let output = Voltage::from(a == b);
+        
+        self.output_pins["out"].borrow_mut().pull(output, None)?;
+        
+        Ok(())
+    }
+}
+
+/// GreaterThan16 - out=1 iff a > b, comparing as two's complement when signed
+/// Registered as "SGreater16" (signed) and "UGreater16" (unsigned)
+#[derive(Debug)]
+pub struct GreaterThan16Chip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    signed: bool,
+}
+
+impl GreaterThan16Chip {
+    pub fn new(signed: bool) -> Self {
+        let name = if signed { "SGreater16" } else { "UGreater16" };
+        let mut chip = Self {
+            name: name.to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+            signed,
+        };
+        
+        let a_pin = Rc::new(RefCell::new(Bus::new("a".to_string(), 16)));
+        let b_pin = Rc::new(RefCell::new(Bus::new("b".to_string(), 16)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 1)));
+        
+        chip.input_pins.insert("a".to_string(), a_pin);
+        chip.input_pins.insert("b".to_string(), b_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+    
+    pub fn is_signed(&self) -> bool {
+        self.signed
+    }
+}
+
+impl ChipInterface for GreaterThan16Chip {
+    impl_chip_interface_boilerplate!("GREATER_THAN16", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.signed)))
+    }
+
+    fn eval(&mut self) -> Result<()> {
+        let a = self.input_pins["a"].borrow().bus_voltage();
+        let b = self.input_pins["b"].borrow().bus_voltage();
+        let output = Voltage::from(compare(a, b, self.signed) == Ordering::Greater);
+        
+        self.output_pins["out"].borrow_mut().pull(output, None)?;
+        
+        Ok(())
+    }
+}
+
+/// Comparator16 - exactly one of lt, eq, gt is 1, for a < b, a == b and a > b
+/// comparing as two's complement when signed
+/// Registered as "SComparator16" (signed) and "Comparator16" (unsigned)
+#[derive(Debug)]
+pub struct Comparator16Chip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    signed: bool,
+}
+
+impl Comparator16Chip {
+    pub fn new(signed: bool) -> Self {
+        let name = if signed { "SComparator16" } else { "Comparator16" };
+        let mut chip = Self {
+            name: name.to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+            signed,
+        };
+        
+        for input in ["a", "b"] {
+            chip.input_pins.insert(input.to_string(), Rc::new(RefCell::new(Bus::new(input.to_string(), 16))));
+        }
+        for output in ["lt", "eq", "gt"] {
+            chip.output_pins.insert(output.to_string(), Rc::new(RefCell::new(Bus::new(output.to_string(), 1))));
+        }
+        
+        chip
+    }
+    
+    pub fn is_signed(&self) -> bool {
+        self.signed
+    }
+}
+
+impl ChipInterface for Comparator16Chip {
+    impl_chip_interface_boilerplate!("COMPARATOR16", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.signed)))
+    }
+
+    fn eval(&mut self) -> Result<()> {
+        let a = self.input_pins["a"].borrow().bus_voltage();
+        let b = self.input_pins["b"].borrow().bus_voltage();
+        let ordering = compare(a, b, self.signed);
+        
+        self.output_pins["lt"].borrow_mut().pull(Voltage::from(ordering == Ordering::Less), None)?;
+        self.output_pins["eq"].borrow_mut().pull(Voltage::from(ordering == Ordering::Equal), None)?;
+        self.output_pins["gt"].borrow_mut().pull(Voltage::from(ordering == Ordering::Greater), None)?;
+        
+        Ok(())
+    }
+}
+
+impl Default for Equal16Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
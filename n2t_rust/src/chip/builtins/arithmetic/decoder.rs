@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::error::{Result, SimulatorError};
+
+/// k-to-2^k decoder: `in[k]`, `out[2^k]` with only bit `in` of `out` set
+/// k is 1..=4 so the one-hot output fits a 16-bit bus
+/// Registered with the builder as "Decoder<k>", e.g. "Decoder3" for a 3-to-8 decoder
+#[derive(Debug)]
+pub struct DecoderChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    width: usize,
+}
+
+impl DecoderChip {
+    pub fn new(width: usize) -> Result<Self> {
+        if width == 0 || width > 4 {
+            return Err(SimulatorError::Hardware(
+                format!("Decoder input width must be between 1 and 4 bits, got {}", width)
+            ));
+        }
+        
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), width))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 1 << width))) as Rc<RefCell<dyn Pin>>);
+        
+        Ok(Self {
+            name: format!("Decoder{}", width),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            width,
+        })
+    }
+    
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl ChipInterface for DecoderChip {
+    impl_chip_interface_boilerplate!("DECODER", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.width)?))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        let index = self.input_pins["in"].borrow().bus_voltage();
+        self.output_pins["out"].borrow_mut().set_bus_voltage(1 << index);
+        Ok(())
+    }
+}
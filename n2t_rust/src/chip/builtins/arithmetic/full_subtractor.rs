@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::pin::{Voltage, HIGH, LOW};
+use crate::error::Result;
+
+basic_chip_struct!(FullSubtractorChip);
+
+impl FullSubtractorChip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "FullSubtractor".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let a_pin = Rc::new(RefCell::new(Bus::new("a".to_string(), 1)));
+        let b_pin = Rc::new(RefCell::new(Bus::new("b".to_string(), 1)));
+        let bin_pin = Rc::new(RefCell::new(Bus::new("bin".to_string(), 1)));
+        let diff_pin = Rc::new(RefCell::new(Bus::new("diff".to_string(), 1)));
+        let bout_pin = Rc::new(RefCell::new(Bus::new("bout".to_string(), 1)));
+        
+        chip.input_pins.insert("a".to_string(), a_pin);
+        chip.input_pins.insert("b".to_string(), b_pin);
+        chip.input_pins.insert("bin".to_string(), bin_pin);
+        chip.output_pins.insert("diff".to_string(), diff_pin);
+        chip.output_pins.insert("bout".to_string(), bout_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for FullSubtractorChip {
+    impl_chip_interface_boilerplate!("FullSubtractor");
+    
+    fn eval(&mut self) -> Result<()> {
+        let a = self.input_pins["a"].borrow().voltage(None)?;
+        let b = self.input_pins["b"].borrow().voltage(None)?;
+        let bin = self.input_pins["bin"].borrow().voltage(None)?;
+        
+        // Full subtractor logic (a - b - bin):
+        // diff = a XOR b XOR bin
+        // bout = borrow out of (a - b), or borrow out of ((a XOR b) - bin)
+        let d = Voltage::from(a != b);
+        let diff = Voltage::from(d != bin);
+        let bout = Voltage::from((a == LOW && b == HIGH) || (d == LOW && bin == HIGH));
+        
+        self.output_pins["diff"].borrow_mut().pull(diff, None)?;
+        self.output_pins["bout"].borrow_mut().pull(bout, None)?;
+        
+        Ok(())
+    }
+}
+
+impl Default for FullSubtractorChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::pin::{Voltage, HIGH, LOW};
+use crate::error::Result;
+
+basic_chip_struct!(HalfSubtractorChip);
+
+impl HalfSubtractorChip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "HalfSubtractor".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let a_pin = Rc::new(RefCell::new(Bus::new("a".to_string(), 1)));
+        let b_pin = Rc::new(RefCell::new(Bus::new("b".to_string(), 1)));
+        let diff_pin = Rc::new(RefCell::new(Bus::new("diff".to_string(), 1)));
+        let borrow_pin = Rc::new(RefCell::new(Bus::new("borrow".to_string(), 1)));
+        
+        chip.input_pins.insert("a".to_string(), a_pin);
+        chip.input_pins.insert("b".to_string(), b_pin);
+        chip.output_pins.insert("diff".to_string(), diff_pin);
+        chip.output_pins.insert("borrow".to_string(), borrow_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for HalfSubtractorChip {
+    impl_chip_interface_boilerplate!("HalfSubtractor");
+    
+    fn eval(&mut self) -> Result<()> {
+        let a = self.input_pins["a"].borrow().voltage(None)?;
+        let b = self.input_pins["b"].borrow().voltage(None)?;
+        
+        // Half subtractor logic (a - b):
+        // diff = a XOR b (true when exactly one input is HIGH)
+        // borrow = Not(a) AND b (true when subtracting 1 from 0)
+        let diff = Voltage::from(a != b);
+        let borrow = Voltage::from(a == LOW && b == HIGH);
+        
+        self.output_pins["diff"].borrow_mut().pull(diff, None)?;
+        self.output_pins["borrow"].borrow_mut().pull(borrow, None)?;
+        
+        Ok(())
+    }
+}
+
+impl Default for HalfSubtractorChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
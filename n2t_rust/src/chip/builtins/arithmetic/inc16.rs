@@ -2,40 +2,127 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
 use crate::chip::{ChipInterface, Bus, Pin};
-use crate::error::Result;
-use super::super::{basic_chip_struct, impl_chip_interface_boilerplate};
+use crate::error::{Result, SimulatorError};
 
-basic_chip_struct!(Inc16Chip);
+// Increment modulo 2^width
+fn increment(value: u16, width: usize) -> u16 {
+    let mask = ((1u32 << width) - 1) as u16;
+    value.wrapping_add(1) & mask
+}
 
-impl Inc16Chip {
-    pub fn new() -> Self {
-        let mut chip = Self {
-            name: "Inc16".to_string(),
-            input_pins: HashMap::new(),
-            output_pins: HashMap::new(),
-            internal_pins: HashMap::new(),
-        };
+/// N-bit incrementer - `out = in + 1` modulo 2^width, for widths 1..=16
+/// Registered with the builder as "Inc<width>", e.g. "Inc4"; "Inc16" is `Inc16Chip`
+#[derive(Debug)]
+pub struct IncNChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    width: usize,
+}
+
+impl IncNChip {
+    pub fn new(width: usize) -> Result<Self> {
+        if width == 0 || width > 16 {
+            return Err(SimulatorError::Hardware(
+                format!("Inc width must be between 1 and 16 bits, got {}", width)
+            ));
+        }
         
-        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
-        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 16)));
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
         
-        chip.input_pins.insert("in".to_string(), in_pin);
-        chip.output_pins.insert("out".to_string(), out_pin);
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), width))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), width))) as Rc<RefCell<dyn Pin>>);
         
-        chip
+        Ok(Self {
+            name: format!("Inc{}", width),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            width,
+        })
+    }
+    
+    pub fn width(&self) -> usize {
+        self.width
     }
 }
 
-impl ChipInterface for Inc16Chip {
-    impl_chip_interface_boilerplate!("Inc16");
+impl ChipInterface for IncNChip {
+    impl_chip_interface_boilerplate!("IncN", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.width)?))
+    }
     
     fn eval(&mut self) -> Result<()> {
         let n = self.input_pins["in"].borrow().bus_voltage();
-        
-        // Increment the 16-bit value with wrapping to handle overflow
-        let output = n.wrapping_add(1) & 0xffff;
-        
-        self.output_pins["out"].borrow_mut().set_bus_voltage(output);
+        self.output_pins["out"].borrow_mut().set_bus_voltage(increment(n, self.width));
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// 16-bit incrementer, the "Inc16" builtin: an `IncNChip` at width 16
+#[derive(Debug)]
+pub struct Inc16Chip {
+    inner: IncNChip,
+}
+
+impl Inc16Chip {
+    pub fn new() -> Self {
+        Self { inner: IncNChip::new(16).expect("16 is a valid Inc width") }
+    }
+}
+
+impl Default for Inc16Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChipInterface for Inc16Chip {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+    
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        self.inner.input_pins()
+    }
+    
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        self.inner.output_pins()
+    }
+    
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        self.inner.internal_pins()
+    }
+    
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        self.inner.get_pin(name)
+    }
+    
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.inner.is_input_pin(name)
+    }
+    
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.inner.is_output_pin(name)
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        self.inner.eval()
+    }
+    
+    fn reset(&mut self) -> Result<()> {
+        self.inner.reset()
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new()))
+    }
+    
+    fn is_combinational(&self) -> bool {
+        self.inner.is_combinational()
+    }
+}
@@ -8,7 +8,16 @@ pub mod add16;
 pub mod inc16;
 pub mod half_adder;
 pub mod full_adder;
+pub mod half_subtractor;
+pub mod full_subtractor;
 pub mod alu;
+pub mod reduce;
+pub mod compare;
+pub mod shift;
+pub mod bits;
+pub mod decoder;
+pub mod sign_extend;
+pub mod tristate;
 
 // Re-export all arithmetic chips
 pub use not16::Not16Chip;
@@ -16,7 +25,16 @@ pub use and16::And16Chip;
 pub use or16::Or16Chip;
 pub use mux16::{Mux16Chip, Mux4Way16Chip, Mux8Way16Chip};
 pub use add16::Add16Chip;
-pub use inc16::Inc16Chip;
+pub use inc16::{Inc16Chip, IncNChip};
 pub use half_adder::HalfAdderChip;
 pub use full_adder::FullAdderChip;
-pub use alu::{AluChip, AluFlags};
\ No newline at end of file
+pub use half_subtractor::HalfSubtractorChip;
+pub use full_subtractor::FullSubtractorChip;
+pub use alu::{AluChip, AluControl, AluFlags};
+pub use reduce::{OrReduceChip, AndReduceChip};
+pub use compare::{Equal16Chip, GreaterThan16Chip, Comparator16Chip};
+pub use shift::{ShiftLeft16Chip, ShiftRight16Chip};
+pub use bits::{Reverse16Chip, ByteSwap16Chip, PopCount16Chip, PriorityEncoder16Chip};
+pub use decoder::DecoderChip;
+pub use sign_extend::SignExtendChip;
+pub use tristate::TriStateBufferChip;
\ No newline at end of file
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::pin::Voltage;
+use crate::error::Result;
+
+basic_chip_struct!(OrReduceChip);
+
+impl OrReduceChip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "OrReduce16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 1)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for OrReduceChip {
+    impl_chip_interface_boilerplate!("OR_REDUCE16");
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        let output = Voltage::from(input != 0); // Any bit set
+        
+        self.output_pins["out"].borrow_mut().pull(output, None)?;
+        
+        Ok(())
+    }
+}
+
+basic_chip_struct!(AndReduceChip);
+
+impl AndReduceChip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "AndReduce16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 1)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for AndReduceChip {
+    impl_chip_interface_boilerplate!("AND_REDUCE16");
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        let output = Voltage::from(input == 0xffff); // Every bit set
+        
+        self.output_pins["out"].borrow_mut().pull(output, None)?;
+        
+        Ok(())
+    }
+}
+
+impl Default for OrReduceChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for AndReduceChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::error::Result;
+
+basic_chip_struct!(ShiftLeft16Chip);
+
+impl ShiftLeft16Chip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "ShiftLeft16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let shamt_pin = Rc::new(RefCell::new(Bus::new("shamt".to_string(), 4)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 16)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.input_pins.insert("shamt".to_string(), shamt_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+}
+
+impl ChipInterface for ShiftLeft16Chip {
+    impl_chip_interface_boilerplate!("SHIFT_LEFT16");
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        let shamt = self.input_pins["shamt"].borrow().bus_voltage() & 0xf;
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(input << shamt);
+        
+        Ok(())
+    }
+}
+
+/// ShiftRight16 - logical shift by default; the arithmetic variant copies the sign bit in
+/// Registered as "ShiftRight16" (logical) and "ShiftRightArith16" (arithmetic)
+#[derive(Debug)]
+pub struct ShiftRight16Chip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    arithmetic: bool,
+}
+
+impl ShiftRight16Chip {
+    pub fn new() -> Self {
+        Self::with_mode("ShiftRight16", false)
+    }
+    
+    pub fn new_arithmetic() -> Self {
+        Self::with_mode("ShiftRightArith16", true)
+    }
+    
+    fn with_mode(name: &str, arithmetic: bool) -> Self {
+        let mut chip = Self {
+            name: name.to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+            arithmetic,
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let shamt_pin = Rc::new(RefCell::new(Bus::new("shamt".to_string(), 4)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 16)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.input_pins.insert("shamt".to_string(), shamt_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+    
+    pub fn is_arithmetic(&self) -> bool {
+        self.arithmetic
+    }
+}
+
+impl ChipInterface for ShiftRight16Chip {
+    impl_chip_interface_boilerplate!("SHIFT_RIGHT16", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::with_mode(&self.name, self.arithmetic)))
+    }
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        let shamt = self.input_pins["shamt"].borrow().bus_voltage() & 0xf;
+        let output = if self.arithmetic {
+            ((input as i16) >> shamt) as u16
+        } else {
+            input >> shamt
+        };
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(output);
+        
+        Ok(())
+    }
+}
+
+impl Default for ShiftLeft16Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for ShiftRight16Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::error::{Result, SimulatorError};
+
+/// Sign extension - `out[out_width]` is `in[in_width]` with its MSB copied into the high bits
+/// Registered with the builder as "SignExtend<in>to<out>", e.g. "SignExtend8to16"
+#[derive(Debug)]
+pub struct SignExtendChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    in_width: usize,
+    out_width: usize,
+}
+
+impl SignExtendChip {
+    pub fn new(in_width: usize, out_width: usize) -> Result<Self> {
+        if in_width == 0 || in_width > out_width || out_width > 16 {
+            return Err(SimulatorError::Hardware(format!(
+                "SignExtend needs 1 <= in width <= out width <= 16, got {} to {}", in_width, out_width
+            )));
+        }
+        
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), in_width))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), out_width))) as Rc<RefCell<dyn Pin>>);
+        
+        Ok(Self {
+            name: format!("SignExtend{}to{}", in_width, out_width),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            in_width,
+            out_width,
+        })
+    }
+    
+    pub fn in_width(&self) -> usize {
+        self.in_width
+    }
+    
+    pub fn out_width(&self) -> usize {
+        self.out_width
+    }
+}
+
+impl ChipInterface for SignExtendChip {
+    impl_chip_interface_boilerplate!("SIGN_EXTEND", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.in_width, self.out_width)?))
+    }
+
+    fn eval(&mut self) -> Result<()> {
+        let input = self.input_pins["in"].borrow().bus_voltage();
+        
+        // Shift the input's MSB up to bit 15, then shift back arithmetically
+        let unused = 16 - self.in_width;
+        let extended = ((input << unused) as i16 >> unused) as u16;
+        let mask = ((1u32 << self.out_width) - 1) as u16;
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(extended & mask);
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::pin::HIGH;
+use crate::error::Result;
+
+basic_chip_struct!(TriStateBufferChip);
+
+impl TriStateBufferChip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "TriState16".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        let in_pin = Rc::new(RefCell::new(Bus::new("in".to_string(), 16)));
+        let enable_pin = Rc::new(RefCell::new(Bus::new("enable".to_string(), 1)));
+        let out_pin = Rc::new(RefCell::new(Bus::new("out".to_string(), 16)));
+        
+        chip.input_pins.insert("in".to_string(), in_pin);
+        chip.input_pins.insert("enable".to_string(), enable_pin);
+        chip.output_pins.insert("out".to_string(), out_pin);
+        
+        chip
+    }
+}
+
+/// Drives `out` from `in` while `enable` is HIGH; otherwise `out` is left floating
+/// so another driver can share the bus
+impl ChipInterface for TriStateBufferChip {
+    impl_chip_interface_boilerplate!("TRI_STATE16");
+
+    fn eval(&mut self) -> Result<()> {
+        let enable = self.input_pins["enable"].borrow().voltage(None)?;
+        
+        if enable == HIGH {
+            let input = self.input_pins["in"].borrow().bus_voltage();
+            self.output_pins["out"].borrow_mut().set_bus_voltage(input);
+        } else {
+            self.output_pins["out"].borrow_mut().float();
+        }
+        
+        Ok(())
+    }
+}
+
+impl Default for TriStateBufferChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
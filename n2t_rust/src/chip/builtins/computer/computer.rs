@@ -0,0 +1,522 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::path::Path;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::pin::{Voltage, HIGH, LOW};
+use crate::error::Result;
+use super::super::arithmetic::{AluChip, AluFlags};
+use super::super::sequential::{ClockedChip, Memory, hash_state};
+use super::{Rom32kChip, ScreenChip, KeyboardChip, jump_taken, SCREEN_OFFSET, KEYBOARD_OFFSET};
+
+const RAM_SIZE: usize = 16384; // 2^14 = 16384 data registers below the screen
+
+/// Device selected by a data memory address, with the device-local offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemTarget {
+    Ram(u16),
+    Screen(u16),
+    Keyboard,
+}
+
+/// Decode a 15-bit data memory address following the Hack memory map:
+/// RAM below the screen, the screen map from SCREEN_OFFSET, the keyboard from KEYBOARD_OFFSET
+/// Addresses above the keyboard register are folded onto it
+pub fn decode_address(addr: u16) -> MemTarget {
+    let addr = (addr & 0x7fff) as usize;
+    if addr >= KEYBOARD_OFFSET {
+        MemTarget::Keyboard
+    } else if addr >= SCREEN_OFFSET {
+        MemTarget::Screen((addr - SCREEN_OFFSET) as u16)
+    } else {
+        MemTarget::Ram(addr as u16)
+    }
+}
+
+/// Computer - the complete Hack machine: CPU, ROM32K, RAM16K, Screen and Keyboard
+/// Executes one instruction per clock cycle; the `reset` input restarts at PC 0
+#[derive(Debug)]
+pub struct ComputerChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    rom: Rom32kChip,
+    ram: Memory,
+    screen: ScreenChip,
+    keyboard: KeyboardChip,
+    // CPU registers
+    a: u16,
+    d: u16,
+    pc: u16,
+    // PC at the start of the last two cycles, most recent first (for halt detection)
+    pc_trace: [u16; 2],
+    cycles: usize,
+    // (addressM, outM) of the last cycle if it asserted writeM
+    last_write: Option<(u16, u16)>,
+}
+
+impl ComputerChip {
+    pub fn new() -> Self {
+        let mut input_pins = HashMap::new();
+
+        input_pins.insert("reset".to_string(), Rc::new(RefCell::new(Bus::new("reset".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+
+        Self {
+            name: "Computer".to_string(),
+            input_pins,
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+            rom: Rom32kChip::new(),
+            ram: Memory::new(RAM_SIZE),
+            screen: ScreenChip::new(),
+            keyboard: KeyboardChip::new(),
+            a: 0,
+            d: 0,
+            pc: 0,
+            pc_trace: [0; 2],
+            cycles: 0,
+            last_write: None,
+        }
+    }
+
+    /// Build a computer with an assembled `.hack` program already in ROM, ready to clock
+    pub fn from_hack_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut computer = Self::new();
+        computer.rom.load_hack_file(path)?;
+        Ok(computer)
+    }
+    
+    /// Load a program into the instruction ROM
+    pub fn load_program(&mut self, program: &[u16]) {
+        self.rom.load_program(program);
+    }
+
+    pub fn rom(&self) -> &Rom32kChip {
+        &self.rom
+    }
+
+    pub fn ram(&self) -> &Memory {
+        &self.ram
+    }
+
+    pub fn screen(&self) -> &ScreenChip {
+        &self.screen
+    }
+
+    pub fn keyboard_mut(&mut self) -> &mut KeyboardChip {
+        &mut self.keyboard
+    }
+
+    pub fn a_register(&self) -> u16 {
+        self.a
+    }
+
+    pub fn d_register(&self) -> u16 {
+        self.d
+    }
+
+    pub fn pc(&self) -> u16 {
+        self.pc
+    }
+
+    /// The ROM word at PC, i.e. the instruction the next cycle executes
+    pub fn current_instruction(&self) -> u16 {
+        self.rom.memory().get(self.pc as usize)
+    }
+
+    /// Address and value written to data memory by the most recent cycle, None if that cycle
+    /// didn't assert writeM. Writes to the keyboard address are reported though they have no effect
+    pub fn last_write(&self) -> Option<(u16, u16)> {
+        self.last_write
+    }
+
+    /// Execute exactly one instruction: eval, then one full clock cycle
+    /// Registers and memory can be inspected between steps
+    pub fn step(&mut self) -> Result<()> {
+        self.eval()?;
+        self.tick(HIGH)?;
+        self.tock(LOW)
+    }
+
+    /// True once the program is parked in an infinite loop
+    /// Recognizes a tight self-jump (PC unchanged across the last cycle) and the
+    /// conventional `(END) @END 0;JMP` idiom, which bounces between two addresses
+    pub fn detect_halt(&self) -> bool {
+        if self.cycles == 0 {
+            return false;
+        }
+
+        if self.pc == self.pc_trace[0] {
+            return true;
+        }
+
+        // `@END` loads its own address and the next instruction jumps there unconditionally
+        let pc = self.pc as usize;
+        self.cycles >= 2
+            && self.pc == self.pc_trace[1]
+            && self.rom.memory().get(pc) == self.pc
+            && self.rom.memory().get(pc + 1) & 0x8007 == 0x8007
+    }
+
+    /// Run `n` full clock cycles, one instruction each
+    /// WASM integration point: a shell calls this once per animation frame, between
+    /// `KeyboardChip::set_key` and `ScreenChip::snapshot`
+    pub fn tick_n(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.tick(HIGH)?;
+            self.tock(LOW)?;
+        }
+        Ok(())
+    }
+
+    /// Clock the computer until it halts or `max_cycles` have elapsed
+    /// Returns the number of cycles run when a halt was detected, None if the budget ran out
+    pub fn run_until_halt(&mut self, max_cycles: usize) -> Result<Option<usize>> {
+        for cycle in 1..=max_cycles {
+            self.tick(HIGH)?;
+            self.tock(LOW)?;
+            if self.detect_halt() {
+                return Ok(Some(cycle));
+            }
+        }
+        Ok(None)
+    }
+
+    // Data memory read following the Hack memory map
+    fn read_memory(&self, address: u16) -> u16 {
+        match decode_address(address) {
+            MemTarget::Ram(offset) => self.ram.get(offset as usize),
+            MemTarget::Screen(offset) => self.screen.memory().get(offset as usize),
+            MemTarget::Keyboard => self.keyboard.get_key(),
+        }
+    }
+
+    // Data memory write following the Hack memory map (the keyboard is read-only)
+    fn write_memory(&mut self, address: u16, value: u16) {
+        match decode_address(address) {
+            MemTarget::Ram(offset) => self.ram.set(offset as usize, value),
+            MemTarget::Screen(offset) => self.screen.set_memory(offset as usize, value),
+            MemTarget::Keyboard => {}
+        }
+    }
+
+    // Execute the instruction at PC, following cpuTick/cpuTock from the TypeScript CPU
+    fn execute(&mut self) {
+        let instruction = self.rom.memory().get(self.pc as usize);
+
+        // A-instruction: load the 15-bit constant into A
+        if instruction & 0x8000 == 0 {
+            self.a = instruction & 0x7fff;
+            self.pc = self.pc.wrapping_add(1) & 0x7fff;
+            return;
+        }
+
+        // C-instruction: a-bit selects M over A as the ALU's y input
+        let y = if instruction & 0x1000 != 0 {
+            self.read_memory(self.a & 0x7fff)
+        } else {
+            self.a
+        };
+        let op = (instruction & 0x0fc0) >> 6;
+        let (result, flags) = AluChip::alu_operation(op, self.d, y);
+
+        // M is addressed by A before this instruction updates it
+        let address = self.a & 0x7fff;
+        if instruction & 0x0008 != 0 {
+            self.write_memory(address, result);
+            self.last_write = Some((address, result));
+        }
+        if instruction & 0x0010 != 0 {
+            self.d = result;
+        }
+
+        let jump = jump_taken(instruction & 0b111, flags == AluFlags::Zero, flags == AluFlags::Negative);
+        self.pc = if jump { address } else { self.pc.wrapping_add(1) & 0x7fff };
+
+        if instruction & 0x0020 != 0 {
+            self.a = result;
+        }
+    }
+}
+
+impl ChipInterface for ComputerChip {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.input_pins
+    }
+
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.output_pins
+    }
+
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.internal_pins
+    }
+
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        if let Some(pin) = self.input_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        Err(crate::error::SimulatorError::PinNotFound {
+            pin: name.to_string(),
+            chip: self.name.clone(),
+        })
+    }
+
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.input_pins.contains_key(name)
+    }
+
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.output_pins.contains_key(name)
+    }
+
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&(self.a, self.d, self.pc, &self.ram, self.screen.memory())))
+    }
+
+    fn eval(&mut self) -> Result<()> {
+        // The computer has no outputs; all state changes happen on the clock
+        Ok(())
+    }
+
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        // Same program in ROM, everything else powered up from zero
+        let memory = self.rom.memory();
+        let program: Vec<u16> = (0..memory.size()).map(|address| memory.get(address)).collect();
+        let mut computer = Self::new();
+        computer.load_program(&program);
+        Ok(Box::new(computer))
+    }
+
+    fn reset(&mut self) -> Result<()> {
+        // Clears CPU state and data memory; the loaded program survives
+        self.ram.reset();
+        self.screen.reset()?;
+        self.keyboard.reset()?;
+        self.a = 0;
+        self.d = 0;
+        self.pc = 0;
+        self.pc_trace = [0; 2];
+        self.cycles = 0;
+        self.last_write = None;
+        Ok(())
+    }
+}
+
+impl ClockedChip for ComputerChip {
+    fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Rising edge: execute one instruction, or restart when reset is held
+        self.pc_trace = [self.pc, self.pc_trace[0]];
+        self.last_write = None;
+        if self.input_pins["reset"].borrow().voltage(None)? == HIGH {
+            self.pc = 0;
+        } else {
+            self.execute();
+        }
+        self.cycles += 1;
+        Ok(())
+    }
+
+    fn tock(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Falling edge: nothing to publish, registers were committed on tick
+        Ok(())
+    }
+
+    fn clear_state(&mut self) {
+        // Data memory and the screen only; the program and CPU registers are kept
+        self.ram.reset();
+        self.screen.clear_state();
+    }
+}
+
+impl Default for ComputerChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_computer_basic_structure() {
+        let computer = ComputerChip::new();
+
+        assert_eq!(computer.name(), "Computer");
+        assert!(computer.get_pin("reset").is_ok());
+        assert_eq!(computer.ram().size(), RAM_SIZE);
+        assert!(!computer.detect_halt(), "Fresh computer has not run yet");
+    }
+
+    #[test]
+    fn test_decode_address() {
+        assert_eq!(decode_address(0), MemTarget::Ram(0));
+        assert_eq!(decode_address(16383), MemTarget::Ram(16383));
+        assert_eq!(decode_address(16384), MemTarget::Screen(0));
+        assert_eq!(decode_address(24575), MemTarget::Screen(8191));
+        assert_eq!(decode_address(24576), MemTarget::Keyboard);
+    }
+
+    #[test]
+    fn test_computer_executes_program() {
+        let mut computer = ComputerChip::new();
+
+        // @2; D=A; @3; D=D+A; @0; M=D
+        computer.load_program(&[0x0002, 0xEC10, 0x0003, 0xE090, 0x0000, 0xE308]);
+        for _ in 0..6 {
+            computer.tick(HIGH).unwrap();
+            computer.tock(LOW).unwrap();
+        }
+
+        assert_eq!(computer.ram().get(0), 5, "RAM[0] should hold 2 + 3");
+    }
+
+    #[test]
+    fn test_computer_single_step() {
+        let mut computer = ComputerChip::new();
+
+        // @5; D=A; @3; D=D+A
+        let program = [0x0005, 0xEC10, 0x0003, 0xE090];
+        computer.load_program(&program);
+        assert_eq!(computer.current_instruction(), 0x0005);
+
+        // (A, D, PC) after each step
+        let expected = [(5, 0, 1), (5, 5, 2), (3, 5, 3), (3, 8, 4)];
+        for (index, &(a, d, pc)) in expected.iter().enumerate() {
+            assert_eq!(computer.current_instruction(), program[index]);
+            computer.step().unwrap();
+            assert_eq!((computer.a_register(), computer.d_register(), computer.pc()), (a, d, pc), "after step {}", index + 1);
+        }
+
+        assert_eq!(computer.d_register(), 8);
+        assert_eq!(computer.pc(), 4);
+    }
+
+    #[test]
+    fn test_computer_last_write() {
+        let mut computer = ComputerChip::new();
+
+        // @7; D=A; @100; M=D; D=D+1; @SCREEN; M=-1
+        computer.load_program(&[0x0007, 0xEC10, 0x0064, 0xE308, 0xE7D0, 0x4000, 0xEE88]);
+        assert_eq!(computer.last_write(), None);
+
+        let mut writes = Vec::new();
+        for _ in 0..7 {
+            computer.step().unwrap();
+            writes.push(computer.last_write());
+        }
+
+        assert_eq!(writes, vec![None, None, None, Some((100, 7)), None, None, Some((0x4000, 0xffff))]);
+        assert_eq!(computer.ram().get(100), 7);
+
+        computer.reset().unwrap();
+        assert_eq!(computer.last_write(), None);
+    }
+
+    #[test]
+    fn test_computer_from_hack_file() {
+        let path = std::env::temp_dir().join(format!("computer_from_hack_{}.hack", std::process::id()));
+        // @5; M=1; M=M+1
+        std::fs::write(&path, "0000000000000101\n1110111111001000\n1111110111001000\n").unwrap();
+        
+        let mut computer = ComputerChip::from_hack_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        for _ in 0..3 {
+            computer.tick(HIGH).unwrap();
+            computer.tock(LOW).unwrap();
+        }
+        
+        assert_eq!(computer.ram().get(5), 2);
+    }
+    
+    #[test]
+    fn test_computer_writes_screen() {
+        let mut computer = ComputerChip::new();
+
+        // @SCREEN; M=-1
+        computer.load_program(&[0x4000, 0xEE88]);
+        for _ in 0..2 {
+            computer.tick(HIGH).unwrap();
+            computer.tock(LOW).unwrap();
+        }
+
+        assert_eq!(computer.screen().memory().get(0), 0xFFFF);
+        assert!(computer.screen().get_pixel(0, 0));
+    }
+
+    #[test]
+    fn test_computer_run_until_halt() {
+        let mut computer = ComputerChip::new();
+
+        // @7; D=A; @0; M=D; (END) @4; 0;JMP
+        computer.load_program(&[0x0007, 0xEC10, 0x0000, 0xE308, 0x0004, 0xEA87]);
+        let cycles = computer.run_until_halt(100).unwrap();
+
+        assert!(cycles.is_some(), "Program ending in a self-loop should halt within budget");
+        assert!(cycles.unwrap() <= 10);
+        assert!(computer.detect_halt());
+        assert_eq!(computer.ram().get(0), 7);
+    }
+
+    #[test]
+    fn test_computer_run_until_halt_budget() {
+        let mut computer = ComputerChip::new();
+
+        // (LOOP) @0; M=M+1; @0; 0;JMP - loops forever but never parks in place
+        computer.load_program(&[0x0000, 0xFDC8, 0x0000, 0xEA87]);
+        let cycles = computer.run_until_halt(50).unwrap();
+
+        assert_eq!(cycles, None, "Budget should run out before a halt is seen");
+    }
+
+    #[test]
+    fn test_computer_headless_frame_loop() {
+        let mut computer = ComputerChip::new();
+
+        // (LOOP) @KBD; D=M; @SCREEN; M=D; @LOOP; 0;JMP - copy the key code to the first screen word
+        computer.load_program(&[0x6000, 0xFC10, 0x4000, 0xE308, 0x0000, 0xEA87]);
+
+        let mut frames = Vec::new();
+        for key in [0, b'A' as u16, 0] {
+            computer.keyboard_mut().set_key(key);
+            computer.tick_n(60).unwrap();
+            frames.push(computer.screen().snapshot());
+        }
+
+        assert_eq!(frames[1].len(), 512 * 256 / 8);
+        assert_eq!(&frames[1][..2], &(b'A' as u16).to_le_bytes());
+        assert!(frames[1][2..].iter().all(|&byte| byte == 0));
+        assert!(frames[0].iter().chain(&frames[2]).all(|&byte| byte == 0), "Releasing the key clears the word");
+    }
+
+    #[test]
+    fn test_computer_reset_input() {
+        let mut computer = ComputerChip::new();
+
+        computer.load_program(&[0x0001, 0x0002, 0x0003]);
+        computer.tick(HIGH).unwrap();
+        computer.tock(LOW).unwrap();
+        computer.tick(HIGH).unwrap();
+        computer.tock(LOW).unwrap();
+
+        computer.get_pin("reset").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+        computer.tick(HIGH).unwrap();
+        computer.tock(LOW).unwrap();
+        computer.get_pin("reset").unwrap().borrow_mut().pull(LOW, None).unwrap();
+
+        // After reset the next instruction executed is ROM[0] (@1)
+        computer.tick(HIGH).unwrap();
+        computer.tock(LOW).unwrap();
+        assert_eq!(computer.a, 1);
+    }
+}
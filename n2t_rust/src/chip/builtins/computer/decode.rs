@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::error::Result;
+
+// Decode - splits a Hack instruction into its control fields
+// A C-instruction `111a cccc ccdd djjj` gives `aBit`, `comp` (a and the six c bits),
+// `dest` and `jump`; for an A-instruction only `isA` is set and every field reads 0,
+// so the fields can drive CPU control lines without further gating
+basic_chip_struct!(DecodeChip);
+
+impl DecodeChip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "Decode".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        chip.input_pins.insert("instruction".to_string(), Rc::new(RefCell::new(Bus::new("instruction".to_string(), 16))));
+        for (name, width) in [("isA", 1), ("isC", 1), ("dest", 3), ("jump", 3), ("comp", 7), ("aBit", 1)] {
+            chip.output_pins.insert(name.to_string(), Rc::new(RefCell::new(Bus::new(name.to_string(), width))));
+        }
+        
+        chip
+    }
+}
+
+impl ChipInterface for DecodeChip {
+    impl_chip_interface_boilerplate!("Decode");
+    
+    fn eval(&mut self) -> Result<()> {
+        let instruction = self.input_pins["instruction"].borrow().bus_voltage();
+        let is_c = instruction >> 15;
+        // Fields are only meaningful for C-instructions
+        let fields = if is_c == 1 { instruction } else { 0 };
+        
+        self.output_pins["isA"].borrow_mut().set_bus_voltage(is_c ^ 1);
+        self.output_pins["isC"].borrow_mut().set_bus_voltage(is_c);
+        self.output_pins["jump"].borrow_mut().set_bus_voltage(fields & 0b111);
+        self.output_pins["dest"].borrow_mut().set_bus_voltage((fields >> 3) & 0b111);
+        self.output_pins["comp"].borrow_mut().set_bus_voltage((fields >> 6) & 0b1111111);
+        self.output_pins["aBit"].borrow_mut().set_bus_voltage((fields >> 12) & 1);
+        
+        Ok(())
+    }
+}
+
+impl Default for DecodeChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
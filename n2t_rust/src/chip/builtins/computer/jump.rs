@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::error::Result;
+
+/// Hack jump condition for the `jump` bits `j1 j2 j3` (jlt, jeq, jgt) of a C-instruction
+/// given the ALU's zr and ng flags: out = (jlt & ng) | (jeq & zr) | (jgt & !zr & !ng)
+pub fn jump_taken(jump: u16, zr: bool, ng: bool) -> bool {
+    let (jlt, jeq, jgt) = (jump & 0b100 != 0, jump & 0b010 != 0, jump & 0b001 != 0);
+    (jlt && ng) || (jeq && zr) || (jgt && !zr && !ng)
+}
+
+// JumpLogic - decides whether the PC loads A, from the ALU flags and the 3 jump bits
+basic_chip_struct!(JumpChip);
+
+impl JumpChip {
+    pub fn new() -> Self {
+        let mut chip = Self {
+            name: "JumpLogic".to_string(),
+            input_pins: HashMap::new(),
+            output_pins: HashMap::new(),
+            internal_pins: HashMap::new(),
+        };
+        
+        for (name, width) in [("zr", 1), ("ng", 1), ("jump", 3)] {
+            chip.input_pins.insert(name.to_string(), Rc::new(RefCell::new(Bus::new(name.to_string(), width))));
+        }
+        chip.output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 1))));
+        
+        chip
+    }
+}
+
+impl ChipInterface for JumpChip {
+    impl_chip_interface_boilerplate!("JumpLogic");
+    
+    fn eval(&mut self) -> Result<()> {
+        let zr = self.input_pins["zr"].borrow().bus_voltage() != 0;
+        let ng = self.input_pins["ng"].borrow().bus_voltage() != 0;
+        let jump = self.input_pins["jump"].borrow().bus_voltage();
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(jump_taken(jump, zr, ng) as u16);
+        
+        Ok(())
+    }
+}
+
+impl Default for JumpChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -39,6 +39,7 @@ impl KeyboardChip {
     }
     
     /// Set the current key value (simulates key press)
+    /// WASM integration point: a shell forwards the held key (0 when none) once per frame
     pub fn set_key(&mut self, key: u16) {
         self.current_key = key & 0xFFFF;
         // Update output immediately
@@ -114,6 +115,15 @@ impl ChipInterface for KeyboardChip {
         Ok(())
     }
     
+    fn is_combinational(&self) -> bool {
+        // The key is set from outside, not derived from inputs
+        false
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new()))
+    }
+    
     fn reset(&mut self) -> Result<()> {
         // Reset clears any pressed key
         self.current_key = 0;
@@ -1,10 +1,19 @@
 // Computer-level components - components needed for the complete computer system
 
 pub mod rom32k;
+pub mod ram_rom;
 pub mod screen;
 pub mod keyboard;
+pub mod decode;
+pub mod jump;
+#[allow(clippy::module_inception)]
+pub mod computer;
 
 // Re-export computer-level chips
 pub use rom32k::Rom32kChip;
-pub use screen::{ScreenChip, SCREEN_SIZE, SCREEN_OFFSET};
-pub use keyboard::{KeyboardChip, KEYBOARD_OFFSET};
\ No newline at end of file
+pub use ram_rom::RamRomChip;
+pub use screen::{ScreenChip, SCREEN_SIZE, SCREEN_OFFSET, SCREEN_WIDTH, SCREEN_HEIGHT};
+pub use keyboard::{KeyboardChip, KEYBOARD_OFFSET};
+pub use decode::DecodeChip;
+pub use jump::{JumpChip, jump_taken};
+pub use computer::{ComputerChip, MemTarget, decode_address};
\ No newline at end of file
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
+use crate::chip::pin::{Voltage, HIGH};
+use crate::error::{Result, SimulatorError};
+use super::super::sequential::{ClockedChip, Memory, hash_state, mask_address};
+
+/// ProgRAM - a ROM32K that a host can reprogram through a write port
+/// Reads are combinational like ROM32K; when `prog` is HIGH on a clock edge, `in` is
+/// written to `address`. Intended for loaders and self-modifying experiments
+#[derive(Debug)]
+pub struct RamRomChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    clock_subscriber: Option<ClockReceiver>,
+    memory: Memory,
+}
+
+impl RamRomChip {
+    pub fn new() -> Self {
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        input_pins.insert("address".to_string(), Rc::new(RefCell::new(Bus::new("address".to_string(), 15))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("prog".to_string(), Rc::new(RefCell::new(Bus::new("prog".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        
+        Self {
+            name: "ProgRAM".to_string(),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            clock_subscriber: None,
+            memory: Memory::new(32768), // 2^15 = 32768 registers, same as ROM32K
+        }
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
+        self.clock_subscriber = Some(clock.subscribe());
+    }
+    
+    /// Load words from address 0, bypassing the write port
+    pub fn load_program(&mut self, program: &[u16]) {
+        for (address, &instruction) in program.iter().enumerate().take(32768) {
+            self.memory.set(address, instruction);
+        }
+    }
+    
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+    
+    // Output the word at the current address
+    fn drive_output(&mut self) {
+        let address = mask_address(self.input_pins["address"].borrow().bus_voltage(), self.memory.address_bits());
+        let value = self.memory.get(address);
+        self.output_pins["out"].borrow_mut().set_bus_voltage(value);
+    }
+}
+
+impl ChipInterface for RamRomChip {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.input_pins
+    }
+    
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.output_pins
+    }
+    
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.internal_pins
+    }
+    
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        if let Some(pin) = self.input_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        if let Some(pin) = self.output_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        Err(SimulatorError::PinNotFound {
+            pin: name.to_string(),
+            chip: self.name.clone(),
+        })
+    }
+    
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.input_pins.contains_key(name)
+    }
+    
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.output_pins.contains_key(name)
+    }
+    
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.memory))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        // Reads are combinational, as in ROM32K
+        self.drive_output();
+        Ok(())
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        // Like ROM32K, the loaded program is structure rather than state
+        let mut rom = Self::new();
+        rom.memory = self.memory.clone();
+        Ok(Box::new(rom))
+    }
+    
+    fn reset(&mut self) -> Result<()> {
+        // Contents survive reset, as in ROM32K
+        self.drive_output();
+        Ok(())
+    }
+}
+
+impl ClockedChip for RamRomChip {
+    fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Rising edge: write through the program port when prog is asserted
+        if self.input_pins["prog"].borrow().voltage(None)? == HIGH {
+            let address = mask_address(self.input_pins["address"].borrow().bus_voltage(), self.memory.address_bits());
+            let value = self.input_pins["in"].borrow().bus_voltage();
+            self.memory.set(address, value);
+        }
+        Ok(())
+    }
+    
+    fn tock(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Falling edge: publish the word at the current address
+        self.drive_output();
+        Ok(())
+    }
+}
+
+impl Default for RamRomChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
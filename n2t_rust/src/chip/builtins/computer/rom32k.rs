@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
+use std::path::Path;
 use crate::chip::{ChipInterface, Bus, Pin};
-use crate::error::Result;
-use super::super::sequential::Memory;
+use crate::error::{Result, SimulatorError};
+use super::super::sequential::{Memory, mask_address};
 
 /// ROM32K - 32768-register ROM using 15-bit address
 /// ROM is read-only memory - load signal has no effect
@@ -34,6 +35,13 @@ impl Rom32kChip {
         }
     }
     
+    /// Create a ROM already holding `words` from address 0
+    pub fn with_program(words: Vec<u16>) -> Self {
+        let mut rom = Self::new();
+        rom.load_program(&words);
+        rom
+    }
+    
     /// Load data into ROM from a vector of instructions
     pub fn load_program(&mut self, program: &[u16]) {
         for (address, &instruction) in program.iter().enumerate() {
@@ -43,6 +51,15 @@ impl Rom32kChip {
         }
     }
     
+    /// Load an assembled `.hack` file: one 16-character binary word per line
+    /// Blank lines are skipped; anything else that isn't a word is a parse error
+    pub fn load_hack_file<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let source = std::fs::read_to_string(path)?;
+        let program = parse_hack(&source)?;
+        self.load_program(&program);
+        Ok(())
+    }
+    
     /// Get current memory for inspection/testing
     pub fn memory(&self) -> &Memory {
         &self.memory
@@ -96,13 +113,20 @@ impl ChipInterface for Rom32kChip {
     
     fn eval(&mut self) -> Result<()> {
         // ROM is pure combinatorial - output data at address immediately
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        let address = address & 0b111111111111111; // Mask to 15 bits for ROM32K
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        let address = mask_address(address, self.memory.address_bits());
         let value = self.memory.get(address);
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
     
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        // The loaded program is part of the ROM's structure, not its state
+        let mut rom = Self::new();
+        rom.memory = self.memory.clone();
+        Ok(Box::new(rom))
+    }
+    
     fn reset(&mut self) -> Result<()> {
         // ROM doesn't clear its contents on reset, just outputs current value at address 0
         self.output_pins["out"].borrow_mut().set_bus_voltage(self.memory.get(0));
@@ -116,9 +140,34 @@ impl Default for Rom32kChip {
     }
 }
 
+// Decode `.hack` text into instruction words
+fn parse_hack(source: &str) -> Result<Vec<u16>> {
+    let mut program = Vec::new();
+    for (index, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.len() != 16 || !line.chars().all(|c| c == '0' || c == '1') {
+            return Err(SimulatorError::Parse(format!(
+                "line {}: expected a 16-bit binary word, found '{}'", index + 1, line
+            )));
+        }
+        program.push(u16::from_str_radix(line, 2).expect("validated binary word"));
+    }
+    
+    if program.len() > 32768 {
+        return Err(SimulatorError::Parse(format!(
+            "program has {} instructions, ROM32K holds 32768", program.len()
+        )));
+    }
+    Ok(program)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chip::{Chip, Connection, PinSide};
     
     #[test]
     fn test_rom32k_basic_structure() {
@@ -220,4 +269,74 @@ mod tests {
             assert_eq!(output, expected, "Program at ROM32K[{}] should be {}", addr, expected);
         }
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_rom32k_with_program() {
+        let mut rom32k = Rom32kChip::with_program(vec![0x0011, 0xEC10, 0xFFFF]);
+        
+        for (addr, expected) in [(0u16, 0x0011u16), (1, 0xEC10), (2, 0xFFFF), (3, 0)] {
+            rom32k.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
+            rom32k.eval().unwrap();
+            let output = rom32k.get_pin("out").unwrap().borrow().bus_voltage();
+            assert_eq!(output, expected, "ROM32K[{}]", addr);
+        }
+    }
+    
+    #[test]
+    fn test_rom32k_reload_then_eval() {
+        // ROM contents change without any input changing, so a host must not skip its eval
+        let mut host = Chip::new("Fetch".to_string());
+        host.add_input_pin("address".to_string(), Rc::new(RefCell::new(Bus::new("address".to_string(), 15))));
+        host.add_output_pin("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))));
+        let connections = ["address", "out"].iter()
+            .map(|name| Connection::new(PinSide::new(name.to_string()), PinSide::new(name.to_string())))
+            .collect();
+        host.wire(Box::new(Rom32kChip::with_program(vec![0x1234])), connections).unwrap();
+        assert!(!host.is_combinational());
+        
+        host.eval().unwrap();
+        host.eval().unwrap();
+        assert_eq!(host.sub_chip_evals(), 2);
+        
+        let mut rom32k = Rom32kChip::with_program(vec![0x1234]);
+        rom32k.eval().unwrap();
+        assert_eq!(rom32k.get_pin("out").unwrap().borrow().bus_voltage(), 0x1234);
+        
+        rom32k.load_program(&[0xBEEF]);
+        rom32k.eval().unwrap();
+        assert_eq!(rom32k.get_pin("out").unwrap().borrow().bus_voltage(), 0xBEEF);
+        assert!(!rom32k.is_combinational());
+    }
+    
+    #[test]
+    fn test_rom32k_load_hack_file() {
+        let path = std::env::temp_dir().join(format!("rom32k_load_{}.hack", std::process::id()));
+        std::fs::write(&path, "0000000000000111\n1110110000010000\n\n1000000000000001\n").unwrap();
+        
+        let mut rom32k = Rom32kChip::new();
+        rom32k.load_hack_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        
+        assert_eq!(rom32k.memory().get(0), 0x0007);
+        assert_eq!(rom32k.memory().get(1), 0xEC10);
+        assert_eq!(rom32k.memory().get(2), 0x8001);
+        assert_eq!(rom32k.memory().get(3), 0);
+    }
+    
+    #[test]
+    fn test_rom32k_parse_hack_errors() {
+        assert!(parse_hack("0000000000000001\n").is_ok());
+        
+        let err = parse_hack("0000000000000001\n@5\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"), "unexpected error: {}", err);
+        assert!(parse_hack("000000000000001\n").is_err(), "15-bit word should be rejected");
+        assert!(parse_hack("0000000000000002\n").is_err());
+    }
+    
+    #[test]
+    fn test_rom32k_load_hack_file_missing() {
+        let mut rom32k = Rom32kChip::new();
+        let result = rom32k.load_hack_file("/nonexistent/program.hack");
+        assert!(matches!(result, Err(SimulatorError::Io(_))));
+    }
+}
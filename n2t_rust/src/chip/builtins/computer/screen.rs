@@ -1,25 +1,31 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
-use crate::chip::{ChipInterface, Clock, Bus, Pin};
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
 use crate::chip::pin::{Voltage, HIGH};
-use crate::error::Result;
-use tokio::sync::broadcast;
-use super::super::sequential::{ClockedChip, Memory};
+use crate::error::{Result, SimulatorError};
+use super::super::sequential::{ClockedChip, Memory, hash_state, mask_address};
 
 pub const SCREEN_SIZE: usize = 8192; // 2^13 = 8192 registers (512x256 pixels / 16 pixels per word)
 pub const SCREEN_OFFSET: usize = 16384; // Screen starts at address 16384 in memory map
+pub const SCREEN_WIDTH: usize = 512; // Pixels per row
+pub const SCREEN_HEIGHT: usize = 256; // Rows
 
 /// Screen - 8192-register screen memory using 13-bit address
 /// Screen is memory-mapped starting at address 16384
+/// Other geometries come from `with_dimensions`; rows are always packed 16 pixels per word
 #[derive(Debug)]
 pub struct ScreenChip {
     name: String,
     input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
-    clock_subscriber: Option<broadcast::Receiver<crate::chip::clock::ClockTick>>,
+    clock_subscriber: Option<ClockReceiver>,
     memory: Memory,
+    // Geometry in pixels; the frame is (width / 16) * height words
+    width: usize,
+    height: usize,
     // Internal state for clocked operation
     next_data: u16,
     current_address: usize,
@@ -27,13 +33,36 @@ pub struct ScreenChip {
 
 impl ScreenChip {
     pub fn new() -> Self {
+        Self::build(SCREEN_WIDTH, SCREEN_HEIGHT)
+    }
+    
+    /// Screen of `width` x `height` pixels; `width` must be a positive multiple of 16 and the
+    /// frame must fit a 15-bit address. Memory is rounded up to a power of two words, so the
+    /// words past the frame are addressable but never displayed
+    pub fn with_dimensions(width: usize, height: usize) -> Result<Self> {
+        if width == 0 || !width.is_multiple_of(16) || height == 0 {
+            return Err(SimulatorError::Hardware(format!(
+                "Screen dimensions must be non-zero with a width that is a multiple of 16, got {}x{}", width, height
+            )));
+        }
+        if (width / 16) * height > 1 << 15 {
+            return Err(SimulatorError::Hardware(format!(
+                "Screen of {}x{} needs more than 32768 words", width, height
+            )));
+        }
+        Ok(Self::build(width, height))
+    }
+    
+    fn build(width: usize, height: usize) -> Self {
+        let memory = Memory::new(((width / 16) * height).next_power_of_two());
+        let address_bits = memory.address_bits();
         let mut input_pins = HashMap::new();
         let mut output_pins = HashMap::new();
         
         // Create pins with trait object casting
         input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
         input_pins.insert("load".to_string(), Rc::new(RefCell::new(Bus::new("load".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
-        input_pins.insert("address".to_string(), Rc::new(RefCell::new(Bus::new("address".to_string(), 13))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("address".to_string(), Rc::new(RefCell::new(Bus::new("address".to_string(), address_bits))) as Rc<RefCell<dyn Pin>>);
         output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
         
         Self {
@@ -42,13 +71,15 @@ impl ScreenChip {
             output_pins,
             internal_pins: HashMap::new(),
             clock_subscriber: None,
-            memory: Memory::new(SCREEN_SIZE),
+            memory,
+            width,
+            height,
             next_data: 0,
             current_address: 0,
         }
     }
     
-    pub fn subscribe_to_clock(&mut self, clock: &Clock) {
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
         self.clock_subscriber = Some(clock.subscribe());
     }
     
@@ -56,15 +87,38 @@ impl ScreenChip {
         &self.memory
     }
     
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    
+    /// Words per row of pixels (32 for the standard screen)
+    pub fn words_per_row(&self) -> usize {
+        self.width / 16
+    }
+    
+    /// Words in the displayed frame (SCREEN_SIZE for the standard screen)
+    pub fn size(&self) -> usize {
+        self.words_per_row() * self.height
+    }
+
+    /// Set a single screen word directly (bypasses the clock)
+    pub fn set_memory(&mut self, address: usize, value: u16) {
+        self.memory.set(address & (self.memory.size() - 1), value);
+    }
+
     /// Get pixel state for a given x, y coordinate
     /// Each memory word represents 16 pixels horizontally
-    /// Screen is 512x256 pixels
+    /// Screen is 512x256 pixels unless built with other dimensions
     pub fn get_pixel(&self, x: usize, y: usize) -> bool {
-        if x >= 512 || y >= 256 {
+        if x >= self.width || y >= self.height {
             return false; // Out of bounds
         }
         
-        let word_address = (y * 32) + (x / 16); // 32 words per row (512/16)
+        let word_address = (y * self.words_per_row()) + (x / 16);
         let bit_position = x % 16;
         let word_value = self.memory.get(word_address);
         
@@ -73,11 +127,11 @@ impl ScreenChip {
     
     /// Set pixel state for a given x, y coordinate
     pub fn set_pixel(&mut self, x: usize, y: usize, value: bool) {
-        if x >= 512 || y >= 256 {
+        if x >= self.width || y >= self.height {
             return; // Out of bounds
         }
         
-        let word_address = (y * 32) + (x / 16);
+        let word_address = (y * self.words_per_row()) + (x / 16);
         let bit_position = x % 16;
         let mut word_value = self.memory.get(word_address);
         
@@ -90,19 +144,79 @@ impl ScreenChip {
         self.memory.set(word_address, word_value);
     }
     
+    /// Set or clear every pixel of the `w` x `h` rectangle with top-left corner (x, y)
+    /// The part outside the screen is clipped; each row is written a word at a time
+    pub fn draw_rect(&mut self, x: usize, y: usize, w: usize, h: usize, value: bool) {
+        let x_end = x.saturating_add(w).min(self.width);
+        let y_end = y.saturating_add(h).min(self.height);
+        if x >= x_end || y >= y_end {
+            return;
+        }
+        
+        for word in x / 16..=(x_end - 1) / 16 {
+            // Bits of this word inside [x, x_end)
+            let low = x.max(word * 16) - word * 16;
+            let high = x_end.min(word * 16 + 16) - word * 16;
+            let mask = (((1u32 << high) - 1) & !((1u32 << low) - 1)) as u16;
+            
+            for row in y..y_end {
+                let address = row * self.words_per_row() + word;
+                let current = self.memory.get(address);
+                self.memory.set(address, if value { current | mask } else { current & !mask });
+            }
+        }
+    }
+    
+    /// Horizontal line of `w` pixels starting at (x, y), clipped to the screen
+    pub fn draw_hline(&mut self, x: usize, y: usize, w: usize, value: bool) {
+        self.draw_rect(x, y, w, 1, value);
+    }
+    
+    /// Vertical line of `h` pixels starting at (x, y), clipped to the screen
+    pub fn draw_vline(&mut self, x: usize, y: usize, h: usize, value: bool) {
+        self.draw_rect(x, y, 1, h, value);
+    }
+    
     /// Clear the entire screen
     pub fn clear_screen(&mut self) {
-        for address in 0..SCREEN_SIZE {
+        for address in 0..self.size() {
             self.memory.set(address, 0);
         }
     }
     
     /// Fill the entire screen
     pub fn fill_screen(&mut self) {
-        for address in 0..SCREEN_SIZE {
+        for address in 0..self.size() {
             self.memory.set(address, 0xFFFF);
         }
     }
+
+    /// Render the framebuffer as a binary PGM (P5) image
+    /// Each pixel becomes one byte: 255 when set, 0 when clear
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let header = format!("P5\n{} {}\n255\n", self.width, self.height);
+        let mut image = Vec::with_capacity(header.len() + self.width * self.height);
+        image.extend_from_slice(header.as_bytes());
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                image.push(if self.get_pixel(x, y) { 255 } else { 0 });
+            }
+        }
+
+        image
+    }
+
+    /// Framebuffer packed one bit per pixel, row by row, width / 8 bytes (64 by default) per row
+    /// Within each byte the leftmost pixel is the least significant bit (the little-endian
+    /// bytes of each screen word). This is the stable surface a WASM shell reads each frame
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.size() * 2);
+        for address in 0..self.size() {
+            bytes.extend_from_slice(&self.memory.get(address).to_le_bytes());
+        }
+        bytes
+    }
 }
 
 impl ChipInterface for ScreenChip {
@@ -143,15 +257,27 @@ impl ChipInterface for ScreenChip {
         self.output_pins.contains_key(name)
     }
     
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.memory))
+    }
+    
     fn eval(&mut self) -> Result<()> {
         // Combinatorial read: output current value at address
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        let address = address & 0b1111111111111; // Mask to 13 bits for Screen
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        let address = mask_address(address, self.memory.address_bits());
         let value = self.memory.get(address);
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
     
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::build(self.width, self.height)))
+    }
+    
     fn reset(&mut self) -> Result<()> {
         self.memory.reset();
         self.next_data = 0;
@@ -165,8 +291,8 @@ impl ClockedChip for ScreenChip {
     fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
         // Rising edge: sample inputs and conditionally write to memory
         let load = self.input_pins["load"].borrow().voltage(None)?;
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        self.current_address = address & 0b1111111111111; // Mask to 13 bits for Screen
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        self.current_address = mask_address(address, self.memory.address_bits());
         
         if load == HIGH {
             self.next_data = self.input_pins["in"].borrow().bus_voltage();
@@ -182,6 +308,10 @@ impl ClockedChip for ScreenChip {
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
+    
+    fn clear_state(&mut self) {
+        self.memory.reset();
+    }
 }
 
 impl Default for ScreenChip {
@@ -282,6 +412,56 @@ mod tests {
         assert_eq!(word_value, expected, "Word should have bits 1, 8, and 15 set");
     }
     
+    #[test]
+    fn test_screen_draw_rect_and_lines() {
+        let mut screen = ScreenChip::new();
+        
+        // Spans a partial word, a whole word and another partial word on each row
+        screen.draw_rect(10, 20, 30, 5, true);
+        for (x, y) in [(10, 20), (39, 20), (10, 24), (39, 24), (16, 22), (31, 22)] {
+            assert!(screen.get_pixel(x, y), "({}, {}) is inside", x, y);
+        }
+        for (x, y) in [(9, 20), (40, 20), (10, 19), (10, 25), (40, 24)] {
+            assert!(!screen.get_pixel(x, y), "({}, {}) is outside", x, y);
+        }
+        assert_eq!((0..SCREEN_HEIGHT).flat_map(|y| (0..SCREEN_WIDTH).map(move |x| (x, y)))
+            .filter(|&(x, y)| screen.get_pixel(x, y)).count(), 30 * 5);
+        
+        // Clearing punches a hole without touching the neighbours
+        screen.draw_hline(12, 22, 3, false);
+        assert!(!screen.get_pixel(12, 22) && !screen.get_pixel(14, 22));
+        assert!(screen.get_pixel(11, 22) && screen.get_pixel(15, 22));
+        
+        screen.draw_vline(100, 0, 3, true);
+        assert!(screen.get_pixel(100, 0) && screen.get_pixel(100, 2));
+        assert!(!screen.get_pixel(100, 3) && !screen.get_pixel(101, 0));
+    }
+    
+    #[test]
+    fn test_screen_draw_clips_to_bounds() {
+        let mut screen = ScreenChip::new();
+        
+        // Runs off the right and bottom edges
+        screen.draw_rect(500, 250, 100, 100, true);
+        assert!(screen.get_pixel(500, 250));
+        assert!(screen.get_pixel(511, 255));
+        assert!(!screen.get_pixel(499, 255));
+        // Nothing wrapped around to the next row or the top of the screen
+        assert!(!screen.get_pixel(0, 251));
+        assert!(!screen.get_pixel(500, 0));
+        
+        // Entirely off screen, empty, or with overflowing extents: no effect, no panic
+        let before = screen.snapshot();
+        screen.draw_rect(600, 10, 5, 5, true);
+        screen.draw_rect(10, 300, 5, 5, true);
+        screen.draw_rect(10, 10, 0, 5, true);
+        screen.draw_hline(usize::MAX, 0, usize::MAX, true);
+        assert_eq!(screen.snapshot(), before);
+        
+        screen.draw_hline(0, 0, usize::MAX, true);
+        assert!(screen.get_pixel(0, 0) && screen.get_pixel(511, 0) && !screen.get_pixel(0, 1));
+    }
+    
     #[test]
     fn test_screen_clear_and_fill() {
         let mut screen = ScreenChip::new();
@@ -334,4 +514,61 @@ mod tests {
         let output = screen.get_pin("out").unwrap().borrow().bus_voltage();
         assert_eq!(output, 0x9999, "Address 8192 should be masked to 0");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_screen_to_pgm() {
+        let mut screen = ScreenChip::new();
+        screen.fill_screen();
+
+        let image = screen.to_pgm();
+        let header = b"P5\n512 256\n255\n";
+        assert_eq!(&image[..header.len()], header, "PGM header should describe a 512x256 image");
+
+        let pixels = &image[header.len()..];
+        assert_eq!(pixels.len(), 512 * 256);
+        assert!(pixels.iter().all(|&p| p == 255), "Filled screen should render every pixel as 255");
+    }
+
+    #[test]
+    fn test_screen_with_dimensions() {
+        let mut screen = ScreenChip::with_dimensions(128, 64).unwrap();
+        assert_eq!((screen.width(), screen.height()), (128, 64));
+        assert_eq!(screen.words_per_row(), 8);
+        assert_eq!(screen.size(), 512);
+        assert_eq!(screen.get_pin("address").unwrap().borrow().width(), 9);
+        
+        // Bottom-right corner: last word of the last row, top bit
+        screen.set_pixel(127, 63, true);
+        assert_eq!(screen.memory().get(63 * 8 + 7), 0x8000);
+        assert!(screen.get_pixel(127, 63));
+        assert!(!screen.get_pixel(128, 63), "past the configured width");
+        screen.set_pixel(0, 1, true);
+        assert_eq!(screen.memory().get(8), 0x0001);
+        
+        let snapshot = screen.snapshot();
+        assert_eq!(snapshot.len(), 128 * 64 / 8);
+        assert_eq!(snapshot[16], 0b0000_0001, "row 1 starts 16 bytes in");
+        assert_eq!(snapshot[snapshot.len() - 1], 0b1000_0000);
+        assert!(screen.to_pgm().starts_with(b"P5\n128 64\n255\n"));
+        
+        assert!(ScreenChip::with_dimensions(100, 64).is_err());
+        assert!(ScreenChip::with_dimensions(0, 64).is_err());
+        assert!(ScreenChip::with_dimensions(4096, 256).is_err());
+        assert_eq!(ScreenChip::new().size(), SCREEN_SIZE);
+    }
+    
+    #[test]
+    fn test_screen_snapshot_packing() {
+        let mut screen = ScreenChip::new();
+        screen.set_pixel(0, 0, true);
+        screen.set_pixel(9, 0, true);
+        screen.set_pixel(511, 255, true);
+
+        let snapshot = screen.snapshot();
+        assert_eq!(snapshot.len(), 512 * 256 / 8, "One bit per pixel");
+        assert_eq!(snapshot[0], 0b0000_0001, "Leftmost pixel is the low bit");
+        assert_eq!(snapshot[1], 0b0000_0010);
+        assert_eq!(snapshot[snapshot.len() - 1], 0b1000_0000);
+        assert_eq!(snapshot.iter().map(|byte| byte.count_ones()).sum::<u32>(), 3);
+    }
+}
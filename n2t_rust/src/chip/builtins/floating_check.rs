@@ -0,0 +1,94 @@
+// Strict floating-input checking for builtins, used by ChipBuilder::strict_floating
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Pin};
+use crate::chip::builtins::ClockedChip;
+use crate::error::{Result, SimulatorError};
+
+/// Wraps a combinational builtin so `eval` fails with `FloatingInput` when an input has never
+/// been driven, instead of computing from a floating pin read as LOW
+/// Everything else passes straight through to the wrapped chip
+#[derive(Debug)]
+pub struct FloatingCheckChip {
+    inner: Box<dyn ChipInterface>,
+}
+
+impl FloatingCheckChip {
+    pub fn new(inner: Box<dyn ChipInterface>) -> Self {
+        Self { inner }
+    }
+    
+    pub fn into_inner(self) -> Box<dyn ChipInterface> {
+        self.inner
+    }
+    
+    fn check_inputs(&self) -> Result<()> {
+        // Sorted so the reported pin doesn't depend on HashMap order
+        for pin in self.inner.input_pin_names_sorted() {
+            if self.inner.input_pins()[&pin].borrow().is_floating() {
+                return Err(SimulatorError::FloatingInput {
+                    pin,
+                    chip: self.inner.name().to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ChipInterface for FloatingCheckChip {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+    
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        self.inner.input_pins()
+    }
+    
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        self.inner.output_pins()
+    }
+    
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        self.inner.internal_pins()
+    }
+    
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        self.inner.get_pin(name)
+    }
+    
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.inner.is_input_pin(name)
+    }
+    
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.inner.is_output_pin(name)
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        self.check_inputs()?;
+        self.inner.eval()
+    }
+    
+    fn reset(&mut self) -> Result<()> {
+        self.inner.reset()
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.inner.fresh()?)))
+    }
+    
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        self.inner.as_clocked_mut()
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        self.inner.state_hash()
+    }
+    
+    fn is_combinational(&self) -> bool {
+        self.inner.is_combinational()
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::error::{Result, SimulatorError};
+
+/// Bitwise operation applied by a BitwiseNChip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitwiseOp {
+    And,
+    Or,
+    Not,
+}
+
+impl BitwiseOp {
+    fn gate_name(&self) -> &'static str {
+        match self {
+            BitwiseOp::And => "And",
+            BitwiseOp::Or => "Or",
+            BitwiseOp::Not => "Not",
+        }
+    }
+}
+
+/// N-bit bitwise gate - `a`, `b` (just `in` for Not) and `out` are `width` bits (1..=16)
+/// Registered with the builder as "<Gate><width>", e.g. "And4", "Or8", "Not12"
+#[derive(Debug)]
+pub struct BitwiseNChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    op: BitwiseOp,
+    width: usize,
+}
+
+impl BitwiseNChip {
+    pub fn new(op: BitwiseOp, width: usize) -> Result<Self> {
+        if width == 0 || width > 16 {
+            return Err(SimulatorError::Hardware(
+                format!("{} width must be between 1 and 16 bits, got {}", op.gate_name(), width)
+            ));
+        }
+        
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        let inputs: &[&str] = if op == BitwiseOp::Not { &["in"] } else { &["a", "b"] };
+        for &pin in inputs {
+            input_pins.insert(pin.to_string(), Rc::new(RefCell::new(Bus::new(pin.to_string(), width))) as Rc<RefCell<dyn Pin>>);
+        }
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), width))) as Rc<RefCell<dyn Pin>>);
+        
+        Ok(Self {
+            name: format!("{}{}", op.gate_name(), width),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            op,
+            width,
+        })
+    }
+    
+    pub fn op(&self) -> BitwiseOp {
+        self.op
+    }
+    
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl ChipInterface for BitwiseNChip {
+    impl_chip_interface_boilerplate!("BITWISE_N", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.op, self.width)?))
+    }
+
+    fn eval(&mut self) -> Result<()> {
+        let mask = ((1u32 << self.width) - 1) as u16;
+        let output = match self.op {
+            BitwiseOp::Not => !self.input_pins["in"].borrow().bus_voltage(),
+            BitwiseOp::And | BitwiseOp::Or => {
+                let a = self.input_pins["a"].borrow().bus_voltage();
+                let b = self.input_pins["b"].borrow().bus_voltage();
+                if self.op == BitwiseOp::And { a & b } else { a | b }
+            }
+        };
+        
+        self.output_pins["out"].borrow_mut().set_bus_voltage(output & mask);
+        
+        Ok(())
+    }
+}
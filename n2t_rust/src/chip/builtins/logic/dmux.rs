@@ -31,6 +31,8 @@ impl DMuxChip {
     }
 }
 
+/// DMux drives all outputs every eval: the selected output follows `in` and
+/// every other output is pulled LOW, so no output keeps a stale value from an earlier `sel`
 impl ChipInterface for DMuxChip {
     impl_chip_interface_boilerplate!("DMUX");
 
@@ -2,9 +2,9 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
 use crate::chip::{ChipInterface, Bus, Pin};
-use crate::chip::pin::{HIGH, LOW};
 use crate::error::Result;
 use super::super::{basic_chip_struct, impl_chip_interface_boilerplate};
+use super::n_way::demux;
 
 basic_chip_struct!(DMux4WayChip);
 
@@ -35,28 +35,12 @@ impl DMux4WayChip {
     }
 }
 
+/// Same routing as DMuxNWayChip with 4 ways
 impl ChipInterface for DMux4WayChip {
     impl_chip_interface_boilerplate!("DMUX4WAY");
 
     fn eval(&mut self) -> Result<()> {
-        let inn = self.input_pins["in"].borrow().voltage(None)?;
-        let sel = self.input_pins["sel"].borrow().bus_voltage();
-        
-        // DMux4Way logic: route input to one of 4 outputs based on 2-bit selector
-        let (a, b, c, d) = match sel & 0b11 {
-            0b00 => (if inn == HIGH { HIGH } else { LOW }, LOW, LOW, LOW),
-            0b01 => (LOW, if inn == HIGH { HIGH } else { LOW }, LOW, LOW),
-            0b10 => (LOW, LOW, if inn == HIGH { HIGH } else { LOW }, LOW),
-            0b11 => (LOW, LOW, LOW, if inn == HIGH { HIGH } else { LOW }),
-            _ => unreachable!(),
-        };
-        
-        self.output_pins["a"].borrow_mut().pull(a, None)?;
-        self.output_pins["b"].borrow_mut().pull(b, None)?;
-        self.output_pins["c"].borrow_mut().pull(c, None)?;
-        self.output_pins["d"].borrow_mut().pull(d, None)?;
-        
-        Ok(())
+        demux(&self.input_pins, &self.output_pins, 4)
     }
 }
 
@@ -97,35 +81,11 @@ impl DMux8WayChip {
     }
 }
 
+/// Same routing as DMuxNWayChip with 8 ways
 impl ChipInterface for DMux8WayChip {
     impl_chip_interface_boilerplate!("DMUX8WAY");
 
     fn eval(&mut self) -> Result<()> {
-        let inn = self.input_pins["in"].borrow().voltage(None)?;
-        let sel = self.input_pins["sel"].borrow().bus_voltage();
-        
-        // DMux8Way logic: route input to one of 8 outputs based on 3-bit selector
-        let (a, b, c, d, e, f, g, h) = match sel & 0b111 {
-            0b000 => (if inn == HIGH { HIGH } else { LOW }, LOW, LOW, LOW, LOW, LOW, LOW, LOW),
-            0b001 => (LOW, if inn == HIGH { HIGH } else { LOW }, LOW, LOW, LOW, LOW, LOW, LOW),
-            0b010 => (LOW, LOW, if inn == HIGH { HIGH } else { LOW }, LOW, LOW, LOW, LOW, LOW),
-            0b011 => (LOW, LOW, LOW, if inn == HIGH { HIGH } else { LOW }, LOW, LOW, LOW, LOW),
-            0b100 => (LOW, LOW, LOW, LOW, if inn == HIGH { HIGH } else { LOW }, LOW, LOW, LOW),
-            0b101 => (LOW, LOW, LOW, LOW, LOW, if inn == HIGH { HIGH } else { LOW }, LOW, LOW),
-            0b110 => (LOW, LOW, LOW, LOW, LOW, LOW, if inn == HIGH { HIGH } else { LOW }, LOW),
-            0b111 => (LOW, LOW, LOW, LOW, LOW, LOW, LOW, if inn == HIGH { HIGH } else { LOW }),
-            _ => unreachable!(),
-        };
-        
-        self.output_pins["a"].borrow_mut().pull(a, None)?;
-        self.output_pins["b"].borrow_mut().pull(b, None)?;
-        self.output_pins["c"].borrow_mut().pull(c, None)?;
-        self.output_pins["d"].borrow_mut().pull(d, None)?;
-        self.output_pins["e"].borrow_mut().pull(e, None)?;
-        self.output_pins["f"].borrow_mut().pull(f, None)?;
-        self.output_pins["g"].borrow_mut().pull(g, None)?;
-        self.output_pins["h"].borrow_mut().pull(h, None)?;
-        
-        Ok(())
+        demux(&self.input_pins, &self.output_pins, 8)
     }
 }
\ No newline at end of file
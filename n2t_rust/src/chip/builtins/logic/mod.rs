@@ -8,6 +8,9 @@ pub mod xor;
 pub mod mux;
 pub mod dmux;
 pub mod dmux_multi;
+pub mod n_way;
+pub mod truth_table;
+pub mod bitwise_n;
 
 // Re-export all logic chips
 pub use nand::NandChip;
@@ -17,4 +20,7 @@ pub use or::OrChip;
 pub use xor::XorChip;
 pub use mux::MuxChip;
 pub use dmux::DMuxChip;
-pub use dmux_multi::{DMux4WayChip, DMux8WayChip};
\ No newline at end of file
+pub use dmux_multi::{DMux4WayChip, DMux8WayChip};
+pub use n_way::{MuxNWayChip, DMuxNWayChip};
+pub use truth_table::TruthTableChip;
+pub use bitwise_n::{BitwiseNChip, BitwiseOp};
\ No newline at end of file
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::pin::LOW;
+use crate::error::{Result, SimulatorError};
+
+const WAY_PINS: [&str; 16] = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p"];
+
+type PinMap = HashMap<String, Rc<RefCell<dyn Pin>>>;
+
+// Smallest select width that can address every way
+fn select_width(ways: usize) -> usize {
+    ways.next_power_of_two().trailing_zeros() as usize
+}
+
+fn check_ways(kind: &str, ways: usize) -> Result<()> {
+    if !(2..=WAY_PINS.len()).contains(&ways) {
+        return Err(SimulatorError::Hardware(
+            format!("{} needs between 2 and {} ways, got {}", kind, WAY_PINS.len(), ways)
+        ));
+    }
+    Ok(())
+}
+
+fn bus(name: &str, width: usize) -> Rc<RefCell<dyn Pin>> {
+    Rc::new(RefCell::new(Bus::new(name.to_string(), width)))
+}
+
+/// Route `in` to the way picked by `sel` and pull every other way LOW, so no output keeps a
+/// stale value; a `sel` past the last way drives every output LOW
+pub(super) fn demux(input_pins: &PinMap, output_pins: &PinMap, ways: usize) -> Result<()> {
+    let inn = input_pins["in"].borrow().voltage(None)?;
+    let sel = input_pins["sel"].borrow().bus_voltage() as usize;
+    
+    for (way, name) in WAY_PINS[..ways].iter().enumerate() {
+        let voltage = if way == sel { inn } else { LOW };
+        output_pins[*name].borrow_mut().pull(voltage, None)?;
+    }
+    
+    Ok(())
+}
+
+/// 1-bit multiplexer over `ways` inputs (2..=16) named `a`, `b`, `c`, ... with a `sel` just
+/// wide enough to address them all; a `sel` past the last way outputs LOW
+/// Registered with the builder as "Mux<ways>Way", e.g. "Mux3Way", "Mux8Way"
+#[derive(Debug)]
+pub struct MuxNWayChip {
+    name: String,
+    input_pins: PinMap,
+    output_pins: PinMap,
+    internal_pins: PinMap,
+    ways: usize,
+}
+
+impl MuxNWayChip {
+    pub fn new(ways: usize) -> Result<Self> {
+        check_ways("Mux", ways)?;
+        
+        let mut input_pins = HashMap::new();
+        for name in &WAY_PINS[..ways] {
+            input_pins.insert(name.to_string(), bus(name, 1));
+        }
+        input_pins.insert("sel".to_string(), bus("sel", select_width(ways)));
+        
+        let mut output_pins = HashMap::new();
+        output_pins.insert("out".to_string(), bus("out", 1));
+        
+        Ok(Self {
+            name: format!("Mux{}Way", ways),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            ways,
+        })
+    }
+    
+    pub fn ways(&self) -> usize {
+        self.ways
+    }
+}
+
+impl ChipInterface for MuxNWayChip {
+    impl_chip_interface_boilerplate!("MUX_N_WAY", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.ways)?))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        let sel = self.input_pins["sel"].borrow().bus_voltage() as usize;
+        let output = match WAY_PINS[..self.ways].get(sel) {
+            Some(name) => self.input_pins[*name].borrow().voltage(None)?,
+            None => LOW,
+        };
+        
+        self.output_pins["out"].borrow_mut().pull(output, None)?;
+        
+        Ok(())
+    }
+}
+
+/// 1-bit demultiplexer onto `ways` outputs (2..=16) named `a`, `b`, `c`, ... with a `sel`
+/// just wide enough to address them all
+/// Registered with the builder as "DMux<ways>Way", e.g. "DMux3Way", "DMux5Way"
+#[derive(Debug)]
+pub struct DMuxNWayChip {
+    name: String,
+    input_pins: PinMap,
+    output_pins: PinMap,
+    internal_pins: PinMap,
+    ways: usize,
+}
+
+impl DMuxNWayChip {
+    pub fn new(ways: usize) -> Result<Self> {
+        check_ways("DMux", ways)?;
+        
+        let mut input_pins = HashMap::new();
+        input_pins.insert("in".to_string(), bus("in", 1));
+        input_pins.insert("sel".to_string(), bus("sel", select_width(ways)));
+        
+        let mut output_pins = HashMap::new();
+        for name in &WAY_PINS[..ways] {
+            output_pins.insert(name.to_string(), bus(name, 1));
+        }
+        
+        Ok(Self {
+            name: format!("DMux{}Way", ways),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            ways,
+        })
+    }
+    
+    pub fn ways(&self) -> usize {
+        self.ways
+    }
+}
+
+impl ChipInterface for DMuxNWayChip {
+    impl_chip_interface_boilerplate!("DMUX_N_WAY", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.ways)?))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        demux(&self.input_pins, &self.output_pins, self.ways)
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::error::{Result, SimulatorError};
+
+/// Combinational chip defined by a truth table instead of HDL
+/// Pins are 1-bit; the first declared pin is the most significant bit of the packed row value
+#[derive(Debug)]
+pub struct TruthTableChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    input_order: Vec<String>,
+    output_order: Vec<String>,
+    // Packed inputs -> packed outputs; rows not listed drive all outputs LOW
+    table: HashMap<u32, u32>,
+}
+
+impl TruthTableChip {
+    pub fn new(name: &str, inputs: &[&str], outputs: &[&str], rows: &[(u32, u32)]) -> Result<Self> {
+        if inputs.len() > 32 || outputs.len() > 32 {
+            return Err(SimulatorError::Hardware(
+                format!("Truth table chip {} supports at most 32 inputs and outputs", name)
+            ));
+        }
+        
+        let mut table = HashMap::new();
+        for &(input, output) in rows {
+            if inputs.len() < 32 && input >> inputs.len() != 0 {
+                return Err(SimulatorError::Hardware(
+                    format!("Row input {:#b} does not fit {} input pins of {}", input, inputs.len(), name)
+                ));
+            }
+            if outputs.len() < 32 && output >> outputs.len() != 0 {
+                return Err(SimulatorError::Hardware(
+                    format!("Row output {:#b} does not fit {} output pins of {}", output, outputs.len(), name)
+                ));
+            }
+            if table.insert(input, output).is_some() {
+                return Err(SimulatorError::Hardware(
+                    format!("Duplicate truth table row {:#b} in {}", input, name)
+                ));
+            }
+        }
+        
+        let mut input_pins: HashMap<String, Rc<RefCell<dyn Pin>>> = HashMap::new();
+        for &pin in inputs {
+            input_pins.insert(pin.to_string(), Rc::new(RefCell::new(Bus::new(pin.to_string(), 1))));
+        }
+        let mut output_pins: HashMap<String, Rc<RefCell<dyn Pin>>> = HashMap::new();
+        for &pin in outputs {
+            output_pins.insert(pin.to_string(), Rc::new(RefCell::new(Bus::new(pin.to_string(), 1))));
+        }
+        
+        Ok(Self {
+            name: name.to_string(),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            input_order: inputs.iter().map(|pin| pin.to_string()).collect(),
+            output_order: outputs.iter().map(|pin| pin.to_string()).collect(),
+            table,
+        })
+    }
+}
+
+impl ChipInterface for TruthTableChip {
+    impl_chip_interface_boilerplate!("TruthTable", no_fresh);
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        let inputs: Vec<&str> = self.input_order.iter().map(|pin| pin.as_str()).collect();
+        let outputs: Vec<&str> = self.output_order.iter().map(|pin| pin.as_str()).collect();
+        let rows: Vec<(u32, u32)> = self.table.iter().map(|(&input, &output)| (input, output)).collect();
+        Ok(Box::new(Self::new(&self.name, &inputs, &outputs, &rows)?))
+    }
+
+    fn eval(&mut self) -> Result<()> {
+        // Pack inputs into the row index, first pin most significant
+        let mut index = 0u32;
+        for name in &self.input_order {
+            let bit = self.input_pins[name].borrow().voltage(None)? as u32;
+            index = (index << 1) | bit;
+        }
+        
+        let packed = self.table.get(&index).copied().unwrap_or(0);
+        let count = self.output_order.len();
+        for (i, name) in self.output_order.iter().enumerate() {
+            let bit = ((packed >> (count - 1 - i)) & 1) as u8;
+            self.output_pins[name].borrow_mut().pull(bit, None)?;
+        }
+        
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
 use crate::chip::{ChipInterface, Bus, Pin};
-use crate::chip::pin::{HIGH, LOW};
+use crate::chip::pin::{Voltage, HIGH};
 use crate::error::Result;
 use super::super::{basic_chip_struct, impl_chip_interface_boilerplate};
 
@@ -36,7 +36,7 @@ impl ChipInterface for XorChip {
         let a = self.input_pins["a"].borrow().voltage(None)?;
         let b = self.input_pins["b"].borrow().voltage(None)?;
         
-        let output = if (a == HIGH) ^ (b == HIGH) { HIGH } else { LOW };
+        let output = Voltage::from((a == HIGH) ^ (b == HIGH));
         
         self.output_pins["out"].borrow_mut().pull(output, None)?;
         
@@ -13,8 +13,16 @@ use crate::chip::{ChipInterface, Bus, Pin};
 use crate::error::Result;
 
 /// Helper macro to implement common ChipInterface methods
+/// Chips whose constructor takes arguments pass `no_fresh` and implement `fresh` themselves
 macro_rules! impl_chip_interface_boilerplate {
     ($chip_name:expr) => {
+        impl_chip_interface_boilerplate!($chip_name, no_fresh);
+        
+        fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+            Ok(Box::new(Self::new()))
+        }
+    };
+    ($chip_name:expr, no_fresh) => {
         fn name(&self) -> &str {
             &self.name
         }
@@ -62,6 +70,11 @@ macro_rules! impl_chip_interface_boilerplate {
             }
             Ok(())
         }
+        
+        // Chips built on this boilerplate hold nothing but their pins
+        fn is_combinational(&self) -> bool {
+            true
+        }
     };
 }
 
@@ -86,9 +99,11 @@ pub mod logic;
 pub mod arithmetic;
 pub mod sequential;
 pub mod computer;
+pub mod floating_check;
 
 // Re-export all chip types for easy access
 pub use logic::*;
 pub use arithmetic::*;
 pub use sequential::*;
-pub use computer::*;
\ No newline at end of file
+pub use computer::*;
+pub use floating_check::FloatingCheckChip;
\ No newline at end of file
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
+use crate::chip::pin::{Voltage, HIGH};
+use crate::error::{Result, SimulatorError};
+use super::{ClockedChip, hash_state};
+
+/// General 16-bit up-counter with enable, load, reset and a terminal-count flag
+/// `tc` is HIGH while the count is 0xFFFF and enabled, i.e. the next tick wraps to 0
+#[derive(Debug)]
+pub struct CounterChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    clock_subscriber: Option<ClockReceiver>,
+    // State - 16-bit count
+    count: u16,
+}
+
+impl CounterChip {
+    pub fn new() -> Self {
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("reset".to_string(), Rc::new(RefCell::new(Bus::new("reset".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("load".to_string(), Rc::new(RefCell::new(Bus::new("load".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("enable".to_string(), Rc::new(RefCell::new(Bus::new("enable".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("tc".to_string(), Rc::new(RefCell::new(Bus::new("tc".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        
+        Self {
+            name: "Counter".to_string(),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            clock_subscriber: None,
+            count: 0,
+        }
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
+        self.clock_subscriber = Some(clock.subscribe());
+    }
+    
+    // Drive out from the count and tc from the count and the current enable
+    fn drive_outputs(&mut self) -> Result<()> {
+        let enable = self.input_pins["enable"].borrow().voltage(None)?;
+        let tc = Voltage::from(self.count == 0xffff && enable == HIGH);
+        self.output_pins["out"].borrow_mut().set_bus_voltage(self.count);
+        self.output_pins["tc"].borrow_mut().set_bus_voltage(tc as u16);
+        Ok(())
+    }
+}
+
+impl ChipInterface for CounterChip {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.input_pins
+    }
+    
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.output_pins
+    }
+    
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.internal_pins
+    }
+    
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        if let Some(pin) = self.input_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        if let Some(pin) = self.output_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        Err(SimulatorError::PinNotFound {
+            pin: name.to_string(),
+            chip: self.name.clone(),
+        })
+    }
+    
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.input_pins.contains_key(name)
+    }
+    
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.output_pins.contains_key(name)
+    }
+    
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.count))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        // Output current state; tc follows enable combinationally
+        self.drive_outputs()
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new()))
+    }
+    
+    fn reset(&mut self) -> Result<()> {
+        self.count = 0;
+        self.drive_outputs()
+    }
+}
+
+impl ClockedChip for CounterChip {
+    fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Rising edge: same priority as PC, reset > load > enable
+        let reset = self.input_pins["reset"].borrow().voltage(None)?;
+        let load = self.input_pins["load"].borrow().voltage(None)?;
+        let enable = self.input_pins["enable"].borrow().voltage(None)?;
+        
+        if reset == HIGH {
+            self.count = 0;
+        } else if load == HIGH {
+            self.count = self.input_pins["in"].borrow().bus_voltage();
+        } else if enable == HIGH {
+            self.count = self.count.wrapping_add(1);
+        }
+        
+        Ok(())
+    }
+    
+    fn tock(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Falling edge: update outputs
+        self.drive_outputs()
+    }
+}
+
+impl Default for CounterChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
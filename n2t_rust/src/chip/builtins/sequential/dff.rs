@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
-use crate::chip::{ChipInterface, Clock, Bus, Pin};
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
 use crate::chip::pin::{Voltage, LOW};
 use crate::error::Result;
-use tokio::sync::broadcast;
-use super::ClockedChip;
+use super::{ClockedChip, hash_state};
 
 /// D Flip-Flop - fundamental sequential building block
 /// On tick: samples input, on tock: outputs previous input
@@ -15,7 +15,7 @@ pub struct DffChip {
     input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
-    clock_subscriber: Option<broadcast::Receiver<crate::chip::clock::ClockTick>>,
+    clock_subscriber: Option<ClockReceiver>,
     // Internal state for two-phase clocking
     stored_value: Voltage,
 }
@@ -41,9 +41,14 @@ impl DffChip {
         }
     }
     
-    pub fn subscribe_to_clock(&mut self, clock: &Clock) {
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
         self.clock_subscriber = Some(clock.subscribe());
     }
+    
+    /// Value sampled on the last tick; `out` only shows it after the following tock
+    pub fn stored_value(&self) -> Voltage {
+        self.stored_value
+    }
 }
 
 impl ChipInterface for DffChip {
@@ -87,12 +92,24 @@ impl ChipInterface for DffChip {
         self.output_pins.contains_key(name)
     }
     
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.stored_value))
+    }
+    
     fn eval(&mut self) -> Result<()> {
         // DFF is sequential - evaluation happens in tick/tock, not here
         // This is called for combinatorial updates but DFF doesn't respond immediately
         Ok(())
     }
     
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new()))
+    }
+    
     fn reset(&mut self) -> Result<()> {
         self.stored_value = LOW;
         self.output_pins["out"].borrow_mut().pull(LOW, None)?;
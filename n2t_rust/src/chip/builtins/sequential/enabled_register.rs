@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
+use crate::chip::pin::{Voltage, HIGH};
+use crate::error::Result;
+use super::{ClockedChip, hash_state};
+
+/// 16-bit Register behind a clock enable, modelling clock gating: while `enable` is HIGH it
+/// behaves like Register, while LOW it ignores clock edges entirely and holds, whatever `load` is
+#[derive(Debug)]
+pub struct EnabledRegisterChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    clock_subscriber: Option<ClockReceiver>,
+    // State - 16-bit value
+    bits: u16,
+}
+
+impl EnabledRegisterChip {
+    pub fn new() -> Self {
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        // Create pins with trait object casting
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("load".to_string(), Rc::new(RefCell::new(Bus::new("load".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("enable".to_string(), Rc::new(RefCell::new(Bus::new("enable".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        
+        Self {
+            name: "EnabledRegister".to_string(),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            clock_subscriber: None,
+            bits: 0,
+        }
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
+        self.clock_subscriber = Some(clock.subscribe());
+    }
+    
+    /// Value latched on the last loading tick; `out` only shows it after the following tock
+    pub fn stored_value(&self) -> u16 {
+        self.bits
+    }
+}
+
+impl ChipInterface for EnabledRegisterChip {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.input_pins
+    }
+    
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.output_pins
+    }
+    
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.internal_pins
+    }
+    
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        if let Some(pin) = self.input_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        if let Some(pin) = self.output_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        Err(crate::error::SimulatorError::PinNotFound {
+            pin: name.to_string(),
+            chip: self.name.clone(),
+        })
+    }
+    
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.input_pins.contains_key(name)
+    }
+    
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.output_pins.contains_key(name)
+    }
+    
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.bits))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        // Output current state (combinatorial read)
+        self.output_pins["out"].borrow_mut().set_bus_voltage(self.bits);
+        Ok(())
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new()))
+    }
+    
+    fn reset(&mut self) -> Result<()> {
+        self.bits = 0;
+        self.output_pins["out"].borrow_mut().set_bus_voltage(0);
+        Ok(())
+    }
+}
+
+impl ClockedChip for EnabledRegisterChip {
+    fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Rising edge: with the clock enabled, conditionally load new value
+        let enable = self.input_pins["enable"].borrow().voltage(None)?;
+        let load = self.input_pins["load"].borrow().voltage(None)?;
+        if enable == HIGH && load == HIGH {
+            self.bits = self.input_pins["in"].borrow().bus_voltage();
+        }
+        Ok(())
+    }
+    
+    fn tock(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Falling edge: update output
+        self.output_pins["out"].borrow_mut().set_bus_voltage(self.bits);
+        Ok(())
+    }
+}
+
+impl Default for EnabledRegisterChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
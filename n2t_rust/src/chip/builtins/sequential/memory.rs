@@ -1,16 +1,24 @@
 /// Basic memory implementation for RAM chips
 /// Stores 16-bit words in an internal array
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash)]
 pub struct Memory {
     data: Vec<u16>,
     size: usize,
+    // Power-on value of every word, restored by reset
+    fill: u16,
 }
 
 impl Memory {
     pub fn new(size: usize) -> Self {
+        Self::new_with_fill(size, 0)
+    }
+    
+    /// Memory whose words start at `fill` instead of 0, so reads of unwritten cells stand out
+    pub fn new_with_fill(size: usize, fill: u16) -> Self {
         Self {
-            data: vec![0; size],
+            data: vec![fill; size],
             size,
+            fill,
         }
     }
     
@@ -29,12 +37,28 @@ impl Memory {
     }
     
     pub fn reset(&mut self) {
-        self.data.fill(0);
+        self.data.fill(self.fill);
+    }
+    
+    pub fn fill(&self) -> u16 {
+        self.fill
     }
     
     pub fn size(&self) -> usize {
         self.size
     }
+    
+    /// Address width that reaches every word; memory sizes are powers of two
+    pub fn address_bits(&self) -> usize {
+        debug_assert!(self.size.is_power_of_two(), "memory size {} is not a power of two", self.size);
+        self.size.trailing_zeros() as usize
+    }
+}
+
+/// Keep the low `bits` bits of an address, the way a memory of 2^bits words decodes it
+/// RAM chips pass `Memory::address_bits` so the mask always matches their size
+pub fn mask_address(addr: u16, bits: usize) -> usize {
+    addr as usize & ((1 << bits) - 1)
 }
 
 #[cfg(test)]
@@ -73,4 +97,20 @@ mod tests {
         memory.set(0, 0x1_2345_u32 as u16); // 17-bit value cast to u16
         assert_eq!(memory.get(0), 0x2345); // Should be masked to 16 bits
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_memory_new_with_fill() {
+        let mut memory = Memory::new_with_fill(4, 0xDEAD);
+        assert_eq!(memory.fill(), 0xDEAD);
+        assert_eq!(memory.get(0), 0xDEAD);
+        assert_eq!(memory.get(3), 0xDEAD);
+        
+        memory.set(2, 0);
+        assert_eq!(memory.get(2), 0);
+        assert_eq!(memory.get(1), 0xDEAD);
+        
+        // Reset restores the fill pattern rather than zero
+        memory.reset();
+        assert_eq!(memory.get(2), 0xDEAD);
+    }
+}
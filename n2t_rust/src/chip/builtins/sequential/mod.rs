@@ -1,5 +1,7 @@
 // Sequential chip implementations - chips with state that respond to clock signals
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use crate::chip::ChipInterface;
 use crate::chip::pin::Voltage;
 use crate::error::Result;
@@ -13,15 +15,31 @@ pub trait ClockedChip: ChipInterface {
     /// Called on falling clock edge (LOW)  
     /// This is when sequential chips should update their outputs
     fn tock(&mut self, clock_level: Voltage) -> Result<()>;
+    
+    /// Clear stored memory contents without touching pins or other state
+    /// Only memories (RAM, the screen) hold contents; everything else keeps the default no-op
+    fn clear_state(&mut self) {}
+}
+
+/// Hash a clocked chip's internal state for `ChipInterface::state_hash`
+pub(crate) fn hash_state<T: Hash>(state: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
 }
 
 pub mod dff;
 pub mod bit;
 pub mod register;
+pub mod enabled_register;
+pub mod register_n;
+pub mod register_file;
 pub mod pc;
+pub mod counter;
 pub mod memory;
 pub mod ram8;
 pub mod ram64;
+pub mod sync_ram64;
 pub mod ram512;
 pub mod ram4k;
 pub mod ram16k;
@@ -30,10 +48,15 @@ pub mod ram16k;
 pub use dff::DffChip;
 pub use bit::BitChip;
 pub use register::RegisterChip;
+pub use enabled_register::EnabledRegisterChip;
+pub use register_n::RegisterNChip;
+pub use register_file::RegisterFileChip;
 pub use pc::PcChip;
-pub use memory::Memory;
+pub use counter::CounterChip;
+pub use memory::{Memory, mask_address};
 pub use ram8::Ram8Chip;
 pub use ram64::Ram64Chip;
+pub use sync_ram64::SyncRam64Chip;
 pub use ram512::Ram512Chip;
 pub use ram4k::Ram4kChip;
 pub use ram16k::Ram16kChip;
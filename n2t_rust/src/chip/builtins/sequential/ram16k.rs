@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
-use crate::chip::{ChipInterface, Clock, Bus, Pin};
+use std::path::Path;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
 use crate::chip::pin::{Voltage, HIGH};
-use crate::error::Result;
-use tokio::sync::broadcast;
-use super::{ClockedChip};
-use super::memory::Memory;
+use crate::error::{Result, SimulatorError};
+use super::{ClockedChip, hash_state};
+use super::memory::{Memory, mask_address};
 
 /// RAM16K - 16384-register RAM using 14-bit address
 #[derive(Debug)]
@@ -15,7 +16,7 @@ pub struct Ram16kChip {
     input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
-    clock_subscriber: Option<broadcast::Receiver<crate::chip::clock::ClockTick>>,
+    clock_subscriber: Option<ClockReceiver>,
     memory: Memory,
     // Internal state for clocked operation
     next_data: u16,
@@ -45,13 +46,56 @@ impl Ram16kChip {
         }
     }
     
-    pub fn subscribe_to_clock(&mut self, clock: &Clock) {
+    /// RAM whose registers power up (and reset) to `fill` instead of 0
+    pub fn with_fill(fill: u16) -> Self {
+        let mut chip = Self::new();
+        chip.memory = Memory::new_with_fill(16384, fill);
+        chip
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
         self.clock_subscriber = Some(clock.subscribe());
     }
     
     pub fn memory(&self) -> &Memory {
         &self.memory
     }
+    
+    /// Seed memory from a text image: one value per line, decimal or `0x` hex, with the
+    /// (zero-based) line number as the address
+    /// The whole file is checked before anything is written, so a bad image leaves memory untouched
+    pub fn load_image(&mut self, path: &Path) -> Result<()> {
+        let source = std::fs::read_to_string(path)?;
+        let image = parse_image(&source, self.memory.size())?;
+        for (address, value) in image.into_iter().enumerate() {
+            self.memory.set(address, value);
+        }
+        Ok(())
+    }
+}
+
+// Decode a memory image into one word per address
+fn parse_image(source: &str, size: usize) -> Result<Vec<u16>> {
+    let mut image = Vec::new();
+    for (index, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if index >= size {
+            return Err(SimulatorError::Parse(format!(
+                "line {}: address {} is past the end of RAM16K ({} words)", index + 1, index, size
+            )));
+        }
+        let value = match line.strip_prefix("0x").or_else(|| line.strip_prefix("0X")) {
+            Some(hex) => u16::from_str_radix(hex, 16),
+            None => line.parse::<u16>(),
+        };
+        match value {
+            Ok(value) => image.push(value),
+            Err(_) => return Err(SimulatorError::Parse(format!(
+                "line {}: expected a 16-bit decimal or hex value, found '{}'", index + 1, line
+            ))),
+        }
+    }
+    Ok(image)
 }
 
 impl ChipInterface for Ram16kChip {
@@ -92,24 +136,27 @@ impl ChipInterface for Ram16kChip {
         self.output_pins.contains_key(name)
     }
     
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.memory))
+    }
+    
     fn eval(&mut self) -> Result<()> {
-        // Get current inputs
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        let address = address & 0b11111111111111; // Mask to 14 bits for RAM16K
-        let load = self.input_pins["load"].borrow().voltage(None)?;
-        
-        // If load is high, write to memory (for testing purposes)
-        if load == HIGH {
-            let data = self.input_pins["in"].borrow().bus_voltage();
-            self.memory.set(address, data);
-        }
-        
-        // Always output current value at address
+        // Combinatorial read only: writes happen on the clock edge in tick
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        let address = mask_address(address, self.memory.address_bits());
         let value = self.memory.get(address);
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
     
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::with_fill(self.memory.fill())))
+    }
+    
     fn reset(&mut self) -> Result<()> {
         self.memory.reset();
         self.next_data = 0;
@@ -123,8 +170,8 @@ impl ClockedChip for Ram16kChip {
     fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
         // Rising edge: sample inputs and conditionally write to memory
         let load = self.input_pins["load"].borrow().voltage(None)?;
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        self.current_address = address & 0b11111111111111; // Mask to 14 bits for RAM16K
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        self.current_address = mask_address(address, self.memory.address_bits());
         
         if load == HIGH {
             self.next_data = self.input_pins["in"].borrow().bus_voltage();
@@ -140,6 +187,10 @@ impl ClockedChip for Ram16kChip {
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
+    
+    fn clear_state(&mut self) {
+        self.memory.reset();
+    }
 }
 
 impl Default for Ram16kChip {
@@ -208,6 +259,34 @@ mod tests {
         assert_eq!(output, 0x1234, "RAM16K[0] should still contain first written value");
     }
     
+    #[test]
+    fn test_ram16k_load_image() {
+        let path = std::env::temp_dir().join(format!("ram16k_image_{}.txt", std::process::id()));
+        std::fs::write(&path, "17\n0x00FF\n65535\n0\n0xbeef\n").unwrap();
+        
+        let mut ram16k = Ram16kChip::new();
+        ram16k.load_image(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        
+        for (address, expected) in [(0, 17), (1, 0x00FF), (2, 0xFFFF), (3, 0), (4, 0xBEEF), (5, 0)] {
+            ram16k.get_pin("address").unwrap().borrow_mut().set_bus_voltage(address);
+            ram16k.eval().unwrap();
+            let output = ram16k.get_pin("out").unwrap().borrow().bus_voltage();
+            assert_eq!(output, expected, "RAM16K[{}] after load_image", address);
+        }
+    }
+    
+    #[test]
+    fn test_ram16k_parse_image_errors() {
+        let err = parse_image("1\n65536\n", 16384).unwrap_err();
+        assert!(err.to_string().contains("line 2"), "unexpected error: {}", err);
+        assert!(parse_image("0x1g\n", 16384).is_err());
+        assert!(parse_image("\n", 16384).is_err(), "blank lines would shift every later address");
+        
+        let err = parse_image("1\n2\n3\n", 2).unwrap_err();
+        assert!(err.to_string().contains("line 3"), "unexpected error: {}", err);
+    }
+    
     #[test]
     fn test_ram16k_address_masking() {
         let mut ram16k = Ram16kChip::new();
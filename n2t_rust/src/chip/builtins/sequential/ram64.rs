@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
-use crate::chip::{ChipInterface, Clock, Bus, Pin};
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
 use crate::chip::pin::{Voltage, HIGH};
 use crate::error::Result;
-use tokio::sync::broadcast;
-use super::{ClockedChip};
-use super::memory::Memory;
+use super::{ClockedChip, hash_state};
+use super::memory::{Memory, mask_address};
 
 /// RAM64 - 64-register RAM using 6-bit address
 #[derive(Debug)]
@@ -15,7 +15,7 @@ pub struct Ram64Chip {
     input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
-    clock_subscriber: Option<broadcast::Receiver<crate::chip::clock::ClockTick>>,
+    clock_subscriber: Option<ClockReceiver>,
     memory: Memory,
     // Internal state for clocked operation
     next_data: u16,
@@ -45,7 +45,14 @@ impl Ram64Chip {
         }
     }
     
-    pub fn subscribe_to_clock(&mut self, clock: &Clock) {
+    /// RAM whose registers power up (and reset) to `fill` instead of 0
+    pub fn with_fill(fill: u16) -> Self {
+        let mut chip = Self::new();
+        chip.memory = Memory::new_with_fill(64, fill);
+        chip
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
         self.clock_subscriber = Some(clock.subscribe());
     }
     
@@ -92,24 +99,27 @@ impl ChipInterface for Ram64Chip {
         self.output_pins.contains_key(name)
     }
     
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.memory))
+    }
+    
     fn eval(&mut self) -> Result<()> {
-        // Get current inputs
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        let address = address & 0b111111; // Mask to 6 bits for RAM64
-        let load = self.input_pins["load"].borrow().voltage(None)?;
-        
-        // If load is high, write to memory (for testing purposes)
-        if load == HIGH {
-            let data = self.input_pins["in"].borrow().bus_voltage();
-            self.memory.set(address, data);
-        }
-        
-        // Always output current value at address
+        // Combinatorial read only: writes happen on the clock edge in tick
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        let address = mask_address(address, self.memory.address_bits());
         let value = self.memory.get(address);
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
     
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::with_fill(self.memory.fill())))
+    }
+    
     fn reset(&mut self) -> Result<()> {
         self.memory.reset();
         self.next_data = 0;
@@ -123,8 +133,8 @@ impl ClockedChip for Ram64Chip {
     fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
         // Rising edge: sample inputs and conditionally write to memory
         let load = self.input_pins["load"].borrow().voltage(None)?;
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        self.current_address = address & 0b111111; // Mask to 6 bits for RAM64
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        self.current_address = mask_address(address, self.memory.address_bits());
         
         if load == HIGH {
             self.next_data = self.input_pins["in"].borrow().bus_voltage();
@@ -140,6 +150,10 @@ impl ClockedChip for Ram64Chip {
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
+    
+    fn clear_state(&mut self) {
+        self.memory.reset();
+    }
 }
 
 impl Default for Ram64Chip {
@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
-use crate::chip::{ChipInterface, Clock, Bus, Pin};
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
 use crate::chip::pin::{Voltage, HIGH};
 use crate::error::Result;
-use tokio::sync::broadcast;
-use super::{ClockedChip};
-use super::memory::Memory;
+use super::{ClockedChip, hash_state};
+use super::memory::{Memory, mask_address};
 
 /// RAM8 - 8-register RAM using 3-bit address
 #[derive(Debug)]
@@ -15,7 +15,7 @@ pub struct Ram8Chip {
     input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
-    clock_subscriber: Option<broadcast::Receiver<crate::chip::clock::ClockTick>>,
+    clock_subscriber: Option<ClockReceiver>,
     memory: Memory,
     // Internal state for clocked operation
     next_data: u16,
@@ -45,7 +45,14 @@ impl Ram8Chip {
         }
     }
     
-    pub fn subscribe_to_clock(&mut self, clock: &Clock) {
+    /// RAM whose registers power up (and reset) to `fill` instead of 0
+    pub fn with_fill(fill: u16) -> Self {
+        let mut chip = Self::new();
+        chip.memory = Memory::new_with_fill(8, fill);
+        chip
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
         self.clock_subscriber = Some(clock.subscribe());
     }
     
@@ -92,24 +99,27 @@ impl ChipInterface for Ram8Chip {
         self.output_pins.contains_key(name)
     }
     
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.memory))
+    }
+    
     fn eval(&mut self) -> Result<()> {
-        // Get current inputs
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        let address = address & 0b111; // Mask to 3 bits for RAM8
-        let load = self.input_pins["load"].borrow().voltage(None)?;
-        
-        // If load is high, write to memory (for testing purposes)
-        if load == HIGH {
-            let data = self.input_pins["in"].borrow().bus_voltage();
-            self.memory.set(address, data);
-        }
-        
-        // Always output current value at address
+        // Combinatorial read only: writes happen on the clock edge in tick
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        let address = mask_address(address, self.memory.address_bits());
         let value = self.memory.get(address);
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
     
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::with_fill(self.memory.fill())))
+    }
+    
     fn reset(&mut self) -> Result<()> {
         self.memory.reset();
         self.next_data = 0;
@@ -123,8 +133,8 @@ impl ClockedChip for Ram8Chip {
     fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
         // Rising edge: sample inputs and conditionally write to memory
         let load = self.input_pins["load"].borrow().voltage(None)?;
-        let address = self.input_pins["address"].borrow().bus_voltage() as usize;
-        self.current_address = address & 0b111; // Mask to 3 bits for RAM8
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        self.current_address = mask_address(address, self.memory.address_bits());
         
         if load == HIGH {
             self.next_data = self.input_pins["in"].borrow().bus_voltage();
@@ -140,6 +150,10 @@ impl ClockedChip for Ram8Chip {
         self.output_pins["out"].borrow_mut().set_bus_voltage(value);
         Ok(())
     }
+    
+    fn clear_state(&mut self) {
+        self.memory.reset();
+    }
 }
 
 impl Default for Ram8Chip {
@@ -286,4 +300,30 @@ mod tests {
             assert_eq!(output, 0, "RAM8[{}] should be 0 after reset", addr);
         }
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_ram8_with_fill() {
+        let mut ram8 = Ram8Chip::with_fill(0xDEAD);
+        
+        // Write only address 3
+        ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(3);
+        ram8.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x0042);
+        ram8.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+        ram8.tick(HIGH).unwrap();
+        ram8.tock(LOW).unwrap();
+        ram8.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
+        
+        for (addr, expected) in [(0, 0xDEAD), (3, 0x0042), (7, 0xDEAD)] {
+            ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
+            ram8.eval().unwrap();
+            let output = ram8.get_pin("out").unwrap().borrow().bus_voltage();
+            assert_eq!(output, expected, "RAM8[{}]", addr);
+        }
+        
+        // Reset returns to the fill pattern
+        ram8.reset().unwrap();
+        ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(3);
+        ram8.eval().unwrap();
+        assert_eq!(ram8.get_pin("out").unwrap().borrow().bus_voltage(), 0xDEAD);
+    }
+}
@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
-use crate::chip::{ChipInterface, Clock, Bus, Pin};
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
 use crate::chip::pin::{Voltage, HIGH};
 use crate::error::Result;
-use tokio::sync::broadcast;
-use super::ClockedChip;
+use super::{ClockedChip, hash_state};
 
 /// 16-bit Register - stores 16 bits with load control
 #[derive(Debug)]
@@ -14,7 +14,7 @@ pub struct RegisterChip {
     input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
-    clock_subscriber: Option<broadcast::Receiver<crate::chip::clock::ClockTick>>,
+    clock_subscriber: Option<ClockReceiver>,
     // State - 16-bit value
     bits: u16,
 }
@@ -39,9 +39,14 @@ impl RegisterChip {
         }
     }
     
-    pub fn subscribe_to_clock(&mut self, clock: &Clock) {
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
         self.clock_subscriber = Some(clock.subscribe());
     }
+    
+    /// Value latched on the last loading tick; `out` only shows it after the following tock
+    pub fn stored_value(&self) -> u16 {
+        self.bits
+    }
 }
 
 impl ChipInterface for RegisterChip {
@@ -82,12 +87,24 @@ impl ChipInterface for RegisterChip {
         self.output_pins.contains_key(name)
     }
     
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.bits))
+    }
+    
     fn eval(&mut self) -> Result<()> {
         // Output current state (combinatorial read)
         self.output_pins["out"].borrow_mut().set_bus_voltage(self.bits);
         Ok(())
     }
     
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new()))
+    }
+    
     fn reset(&mut self) -> Result<()> {
         self.bits = 0;
         self.output_pins["out"].borrow_mut().set_bus_voltage(0);
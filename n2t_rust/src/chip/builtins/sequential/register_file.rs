@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
+use crate::chip::pin::{Voltage, HIGH};
+use crate::error::{Result, SimulatorError};
+use super::{ClockedChip, hash_state};
+
+/// Register file - `regs` 16-bit registers with one write port and one read port
+/// `readAddr` selects `out` combinationally; on a clock edge with `load` HIGH, `in` is stored
+/// in the register at `writeAddr`. Both addresses are ceil(log2(regs)) bits wide, and
+/// addresses past the last register read 0 and ignore writes
+/// Registered with the builder as "RegisterFile<regs>", e.g. "RegisterFile8"
+#[derive(Debug)]
+pub struct RegisterFileChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    clock_subscriber: Option<ClockReceiver>,
+    // State - one word per register
+    registers: Vec<u16>,
+}
+
+impl RegisterFileChip {
+    pub fn new(regs: usize) -> Result<Self> {
+        if !(2..=1 << 16).contains(&regs) {
+            return Err(SimulatorError::Hardware(
+                format!("Register file must hold between 2 and 65536 registers, got {}", regs)
+            ));
+        }
+        let address_width = regs.next_power_of_two().trailing_zeros() as usize;
+        
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("load".to_string(), Rc::new(RefCell::new(Bus::new("load".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("writeAddr".to_string(), Rc::new(RefCell::new(Bus::new("writeAddr".to_string(), address_width))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("readAddr".to_string(), Rc::new(RefCell::new(Bus::new("readAddr".to_string(), address_width))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        
+        Ok(Self {
+            name: format!("RegisterFile{}", regs),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            clock_subscriber: None,
+            registers: vec![0; regs],
+        })
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
+        self.clock_subscriber = Some(clock.subscribe());
+    }
+    
+    pub fn registers(&self) -> &[u16] {
+        &self.registers
+    }
+    
+    // Drive out from the register selected by readAddr
+    fn drive_output(&mut self) {
+        let address = self.input_pins["readAddr"].borrow().bus_voltage() as usize;
+        let value = self.registers.get(address).copied().unwrap_or(0);
+        self.output_pins["out"].borrow_mut().set_bus_voltage(value);
+    }
+}
+
+impl ChipInterface for RegisterFileChip {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.input_pins
+    }
+    
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.output_pins
+    }
+    
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.internal_pins
+    }
+    
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        if let Some(pin) = self.input_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        if let Some(pin) = self.output_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        Err(SimulatorError::PinNotFound {
+            pin: name.to_string(),
+            chip: self.name.clone(),
+        })
+    }
+    
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.input_pins.contains_key(name)
+    }
+    
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.output_pins.contains_key(name)
+    }
+    
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.registers))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        // Combinational read through the read port
+        self.drive_output();
+        Ok(())
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.registers.len())?))
+    }
+    
+    fn reset(&mut self) -> Result<()> {
+        self.registers.fill(0);
+        self.drive_output();
+        Ok(())
+    }
+}
+
+impl ClockedChip for RegisterFileChip {
+    fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Rising edge: write through the write port when load is HIGH
+        if self.input_pins["load"].borrow().voltage(None)? == HIGH {
+            let address = self.input_pins["writeAddr"].borrow().bus_voltage() as usize;
+            let value = self.input_pins["in"].borrow().bus_voltage();
+            if let Some(register) = self.registers.get_mut(address) {
+                *register = value;
+            }
+        }
+        Ok(())
+    }
+    
+    fn tock(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Falling edge: a write to the selected register shows up on out
+        self.drive_output();
+        Ok(())
+    }
+    
+    fn clear_state(&mut self) {
+        self.registers.fill(0);
+    }
+}
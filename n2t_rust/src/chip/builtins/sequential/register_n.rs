@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
+use crate::chip::pin::{Voltage, HIGH};
+use crate::error::{Result, SimulatorError};
+use super::{ClockedChip, hash_state};
+
+/// N-bit Register - stores `width` bits (1..=16) with load control
+/// Registered with the builder as "Register<width>", e.g. "Register8"
+#[derive(Debug)]
+pub struct RegisterNChip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    clock_subscriber: Option<ClockReceiver>,
+    width: usize,
+    // State - masked to width bits
+    bits: u16,
+}
+
+impl RegisterNChip {
+    pub fn new(width: usize) -> Result<Self> {
+        if width == 0 || width > 16 {
+            return Err(SimulatorError::Hardware(
+                format!("Register width must be between 1 and 16 bits, got {}", width)
+            ));
+        }
+        
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        // Create pins with trait object casting
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), width))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("load".to_string(), Rc::new(RefCell::new(Bus::new("load".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), width))) as Rc<RefCell<dyn Pin>>);
+        
+        Ok(Self {
+            name: format!("Register{}", width),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            clock_subscriber: None,
+            width,
+            bits: 0,
+        })
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
+        self.clock_subscriber = Some(clock.subscribe());
+    }
+    
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    
+    fn mask(&self) -> u16 {
+        ((1u32 << self.width) - 1) as u16
+    }
+}
+
+impl ChipInterface for RegisterNChip {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.input_pins
+    }
+    
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.output_pins
+    }
+    
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.internal_pins
+    }
+    
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        if let Some(pin) = self.input_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        if let Some(pin) = self.output_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        Err(SimulatorError::PinNotFound {
+            pin: name.to_string(),
+            chip: self.name.clone(),
+        })
+    }
+    
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.input_pins.contains_key(name)
+    }
+    
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.output_pins.contains_key(name)
+    }
+    
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&self.bits))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        // Output current state (combinatorial read)
+        self.output_pins["out"].borrow_mut().set_bus_voltage(self.bits);
+        Ok(())
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new(self.width)?))
+    }
+    
+    fn reset(&mut self) -> Result<()> {
+        self.bits = 0;
+        self.output_pins["out"].borrow_mut().set_bus_voltage(0);
+        Ok(())
+    }
+}
+
+impl ClockedChip for RegisterNChip {
+    fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Rising edge: conditionally load new value
+        let load = self.input_pins["load"].borrow().voltage(None)?;
+        if load == HIGH {
+            let input_value = self.input_pins["in"].borrow().bus_voltage();
+            self.bits = input_value & self.mask();
+        }
+        Ok(())
+    }
+    
+    fn tock(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Falling edge: update output
+        self.output_pins["out"].borrow_mut().set_bus_voltage(self.bits);
+        Ok(())
+    }
+}
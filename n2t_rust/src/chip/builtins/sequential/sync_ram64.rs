@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::chip::{ChipInterface, Bus, Pin};
+use crate::chip::clock::{ClockReceiver, ClockSource};
+use crate::chip::pin::{Voltage, HIGH};
+use crate::error::Result;
+use super::{ClockedChip, hash_state};
+use super::memory::{Memory, mask_address};
+
+/// SyncRAM64 - 64-register RAM with a registered (synchronous) read port
+/// Pins match RAM64, but `out` ignores the address until the next clock edge: the address
+/// is latched on tick and the word it selects (after any write) appears on tock
+#[derive(Debug)]
+pub struct SyncRam64Chip {
+    name: String,
+    input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    clock_subscriber: Option<ClockReceiver>,
+    memory: Memory,
+    // Address latched on the last tick and the output register it loads on tock
+    read_address: usize,
+    read_data: u16,
+}
+
+impl SyncRam64Chip {
+    pub fn new() -> Self {
+        let mut input_pins = HashMap::new();
+        let mut output_pins = HashMap::new();
+        
+        input_pins.insert("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("load".to_string(), Rc::new(RefCell::new(Bus::new("load".to_string(), 1))) as Rc<RefCell<dyn Pin>>);
+        input_pins.insert("address".to_string(), Rc::new(RefCell::new(Bus::new("address".to_string(), 6))) as Rc<RefCell<dyn Pin>>);
+        output_pins.insert("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))) as Rc<RefCell<dyn Pin>>);
+        
+        Self {
+            name: "SyncRAM64".to_string(),
+            input_pins,
+            output_pins,
+            internal_pins: HashMap::new(),
+            clock_subscriber: None,
+            memory: Memory::new(64), // 2^6 = 64 registers
+            read_address: 0,
+            read_data: 0,
+        }
+    }
+    
+    pub fn subscribe_to_clock(&mut self, clock: &dyn ClockSource) {
+        self.clock_subscriber = Some(clock.subscribe());
+    }
+    
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+}
+
+impl ChipInterface for SyncRam64Chip {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.input_pins
+    }
+    
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.output_pins
+    }
+    
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> {
+        &self.internal_pins
+    }
+    
+    fn get_pin(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        if let Some(pin) = self.input_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        if let Some(pin) = self.output_pins.get(name) {
+            return Ok(pin.clone());
+        }
+        Err(crate::error::SimulatorError::PinNotFound {
+            pin: name.to_string(),
+            chip: self.name.clone(),
+        })
+    }
+    
+    fn is_input_pin(&self, name: &str) -> bool {
+        self.input_pins.contains_key(name)
+    }
+    
+    fn is_output_pin(&self, name: &str) -> bool {
+        self.output_pins.contains_key(name)
+    }
+    
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        Some(self)
+    }
+    
+    fn state_hash(&self) -> Option<u64> {
+        Some(hash_state(&(&self.memory, self.read_address, self.read_data)))
+    }
+    
+    fn eval(&mut self) -> Result<()> {
+        // Registered read: output the word loaded on the last tock, whatever the address now
+        self.output_pins["out"].borrow_mut().set_bus_voltage(self.read_data);
+        Ok(())
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(Self::new()))
+    }
+    
+    fn reset(&mut self) -> Result<()> {
+        self.memory.reset();
+        self.read_address = 0;
+        self.read_data = 0;
+        self.output_pins["out"].borrow_mut().set_bus_voltage(0);
+        Ok(())
+    }
+}
+
+impl ClockedChip for SyncRam64Chip {
+    fn tick(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Rising edge: latch the read address and conditionally write to memory
+        let load = self.input_pins["load"].borrow().voltage(None)?;
+        let address = self.input_pins["address"].borrow().bus_voltage();
+        self.read_address = mask_address(address, self.memory.address_bits());
+        
+        if load == HIGH {
+            let value = self.input_pins["in"].borrow().bus_voltage();
+            self.memory.set(self.read_address, value);
+        }
+        
+        Ok(())
+    }
+    
+    fn tock(&mut self, _clock_level: Voltage) -> Result<()> {
+        // Falling edge: load the output register from the latched address
+        self.read_data = self.memory.get(self.read_address);
+        self.output_pins["out"].borrow_mut().set_bus_voltage(self.read_data);
+        Ok(())
+    }
+    
+    fn clear_state(&mut self) {
+        self.memory.reset();
+    }
+}
+
+impl Default for SyncRam64Chip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chip::pin::{HIGH, LOW};
+    use super::super::Ram64Chip;
+    
+    // Write `value` to `address` on one clock cycle
+    fn write(ram: &mut dyn ClockedChip, address: u16, value: u16) {
+        ram.set_pin("address", address).unwrap();
+        ram.set_pin("in", value).unwrap();
+        ram.set_pin("load", 1).unwrap();
+        ram.tick(HIGH).unwrap();
+        ram.tock(LOW).unwrap();
+        ram.set_pin("load", 0).unwrap();
+    }
+    
+    #[test]
+    fn test_sync_ram64_basic_structure() {
+        let ram = SyncRam64Chip::new();
+        
+        assert_eq!(ram.name(), "SyncRAM64");
+        for pin in ["in", "load", "address", "out"] {
+            assert!(ram.get_pin(pin).is_ok(), "SyncRAM64 should have pin {}", pin);
+        }
+        assert_eq!(ram.pin_width("address").unwrap(), 6);
+        assert_eq!(ram.memory().size(), 64);
+    }
+    
+    #[test]
+    fn test_sync_ram64_read_latency() {
+        let mut sync_ram = SyncRam64Chip::new();
+        let mut ram = Ram64Chip::new();
+        for (address, value) in [(3, 0x0333), (7, 0x0777)] {
+            write(&mut sync_ram, address, value);
+            write(&mut ram, address, value);
+        }
+        
+        // Last write left address 7 selected; move to 3 without clocking
+        sync_ram.set_pin("address", 3).unwrap();
+        ram.set_pin("address", 3).unwrap();
+        sync_ram.eval().unwrap();
+        ram.eval().unwrap();
+        assert_eq!(ram.read_pin("out").unwrap(), 0x0333, "RAM64 reads combinationally");
+        assert_eq!(sync_ram.read_pin("out").unwrap(), 0x0777, "SyncRAM64 still shows the last registered read");
+        
+        // One clock later the new address has been read
+        sync_ram.tick(HIGH).unwrap();
+        sync_ram.tock(LOW).unwrap();
+        sync_ram.eval().unwrap();
+        assert_eq!(sync_ram.read_pin("out").unwrap(), 0x0333);
+    }
+}
@@ -8,7 +8,20 @@ pub struct Bus {
     name: String,
     width: usize,
     state: Vec<Voltage>,
+    // True until something drives the bus, and again after `float`
+    floating: bool,
     connections: Vec<Weak<RefCell<dyn Pin>>>,
+    watchers: ChangeWatchers,
+}
+
+/// Callbacks fired with the new bus value whenever it changes
+#[derive(Default)]
+struct ChangeWatchers(Vec<Box<dyn FnMut(u16)>>);
+
+impl std::fmt::Debug for ChangeWatchers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} watcher(s)", self.0.len())
+    }
 }
 
 impl Bus {
@@ -19,7 +32,9 @@ impl Bus {
             name,
             width,
             state: vec![LOW; width],
+            floating: true,
             connections: Vec::new(),
+            watchers: ChangeWatchers::default(),
         }
     }
     
@@ -38,6 +53,24 @@ impl Bus {
         Ok(())
     }
     
+    /// Register a callback invoked with the new value whenever `set_bus_voltage`, `pull`
+    /// or `float` changes what the bus holds; buses without watchers pay nothing.
+    ///
+    /// Watchers run while the bus is still mutably borrowed, so they must use the value they
+    /// are handed: borrowing the watched bus from inside the callback panics (`try_borrow` fails).
+    pub fn on_change(&mut self, cb: Box<dyn FnMut(u16)>) {
+        self.watchers.0.push(cb);
+    }
+    
+    fn notify_change(&mut self, previous: u16) {
+        let current = self.bus_voltage();
+        if current != previous {
+            for watcher in &mut self.watchers.0 {
+                watcher(current);
+            }
+        }
+    }
+    
     fn propagate_voltage(&mut self, voltage: Voltage, bit: usize) {
         // Remove dead weak references
         self.connections.retain(|weak_pin| weak_pin.strong_count() > 0);
@@ -52,6 +85,20 @@ impl Bus {
         }
     }
     
+    fn propagate_float(&mut self) {
+        // Remove dead weak references
+        self.connections.retain(|weak_pin| weak_pin.strong_count() > 0);
+        
+        // Connected pins lose their driver too
+        for weak_pin in &self.connections {
+            if let Some(pin_ref) = weak_pin.upgrade() {
+                if let Ok(mut pin) = pin_ref.try_borrow_mut() {
+                    pin.float();
+                }
+            }
+        }
+    }
+    
     fn propagate_bus_voltage(&mut self, voltage: u16) {
         // Remove dead weak references
         self.connections.retain(|weak_pin| weak_pin.strong_count() > 0);
@@ -67,6 +114,21 @@ impl Bus {
     }
 }
 
+// Buses compare and hash by state (see `Pin::fingerprint`), not by name or wiring
+impl PartialEq for Bus {
+    fn eq(&self, other: &Self) -> bool {
+        self.fingerprint() == other.fingerprint()
+    }
+}
+
+impl Eq for Bus {}
+
+impl std::hash::Hash for Bus {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.fingerprint().hash(state);
+    }
+}
+
 impl Pin for Bus {
     fn name(&self) -> &str {
         &self.name
@@ -87,9 +149,14 @@ impl Pin for Bus {
     }
     
     fn set_bus_voltage(&mut self, voltage: u16) {
+        let previous = self.bus_voltage();
         for i in 0..self.width {
             self.state[i] = if (voltage & (1 << i)) != 0 { HIGH } else { LOW };
         }
+        self.floating = false;
+        if !self.watchers.0.is_empty() {
+            self.notify_change(previous);
+        }
         self.propagate_bus_voltage(voltage);
     }
     
@@ -102,7 +169,12 @@ impl Pin for Bus {
             ));
         }
         
+        let previous = self.bus_voltage();
         self.state[bit] = voltage;
+        self.floating = false;
+        if !self.watchers.0.is_empty() {
+            self.notify_change(previous);
+        }
         self.propagate_voltage(voltage, bit);
         
         Ok(())
@@ -137,6 +209,21 @@ impl Pin for Bus {
         
         self.connections.push(pin);
     }
+    
+    fn is_floating(&self) -> bool {
+        self.floating
+    }
+    
+    fn float(&mut self) {
+        // Undriven buses read as LOW
+        let previous = self.bus_voltage();
+        self.state.fill(LOW);
+        self.floating = true;
+        if !self.watchers.0.is_empty() {
+            self.notify_change(previous);
+        }
+        self.propagate_float();
+    }
 }
 
 pub struct SubBus {
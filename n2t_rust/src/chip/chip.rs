@@ -1,11 +1,13 @@
 use std::collections::HashMap;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 use std::cell::RefCell;
-use crate::chip::pin::Pin;
-use crate::chip::clock::ClockTick;
+use crate::chip::Bus;
+use crate::chip::pin::{Pin, Voltage, HIGH, ConstantPin, parse_constant_literal};
+use crate::chip::clock::ClockReceiver;
+use crate::chip::builtins::ClockedChip;
 use crate::chip::subbus::{PinRange, create_input_subbus, create_output_subbus};
 use crate::error::{Result, SimulatorError};
-use tokio::sync::broadcast;
 
 /// Represents one side of a wire connection
 #[derive(Debug, Clone)]
@@ -23,12 +25,38 @@ impl PinSide {
         Self { name, range: Some(range) }
     }
     
+    /// Host side driven by a numeric constant: a binary literal zero-extended to the pin it drives
+    pub fn constant(value: u16, width: usize) -> Self {
+        Self { name: format!("%B{:0width$b}", value, width = width), range: None }
+    }
+    
+    /// Host side driven by `true` or `false`; `true` fills every bit it drives
+    pub fn boolean(value: bool) -> Self {
+        Self { name: value.to_string(), range: None }
+    }
+    
     pub fn from_range(range: PinRange) -> Self {
         Self {
             name: range.pin_name.clone(),
             range: Some(range),
         }
     }
+    
+    /// Slice `name[start..end]` from an inclusive Rust range, e.g. `PinSide::bits("in", 0..=7)`
+    pub fn bits(name: &str, bits: RangeInclusive<usize>) -> Self {
+        Self::from_range(PinRange::from_rust(name, bits))
+    }
+    
+    /// Single bit `name[bit]`
+    pub fn bit(name: &str, bit: usize) -> Self {
+        Self::bits(name, bit..=bit)
+    }
+}
+
+impl From<&str> for PinSide {
+    fn from(name: &str) -> Self {
+        Self::new(name.to_string())
+    }
 }
 
 /// Represents a connection between pins or pin ranges
@@ -48,7 +76,8 @@ impl Connection {
 #[derive(Debug, Clone)]
 pub enum WireError {
     PinNotFound { pin_name: String, chip_name: String },
-    WidthMismatch { from_width: usize, to_width: usize, connection: String },
+    /// `from_*` is the host side of the connection and `to_*` the part side, whichever way it drives
+    WidthMismatch { from_pin: String, from_width: usize, to_pin: String, to_width: usize, connection: String },
     InvalidRange { pin_name: String, error: String },
     MultipleAssignment { pin_name: String, conflict: String },
     CircularDependency { cycle: Vec<String> },
@@ -60,8 +89,9 @@ impl std::fmt::Display for WireError {
             WireError::PinNotFound { pin_name, chip_name } => {
                 write!(f, "Pin '{}' not found in chip '{}'", pin_name, chip_name)
             }
-            WireError::WidthMismatch { from_width, to_width, connection } => {
-                write!(f, "Width mismatch in connection '{}': {} -> {}", connection, from_width, to_width)
+            WireError::WidthMismatch { from_pin, from_width, to_pin, to_width, connection } => {
+                write!(f, "Width mismatch in connection '{}': host pin '{}' (width {}) != part pin '{}' (width {})",
+                    connection, from_pin, from_width, to_pin, to_width)
             }
             WireError::InvalidRange { pin_name, error } => {
                 write!(f, "Invalid range for pin '{}': {}", pin_name, error)
@@ -76,6 +106,15 @@ impl std::fmt::Display for WireError {
     }
 }
 
+// A connection side as written in HDL, e.g. `a`, `a[3]` or `a[0..7]`
+fn describe_side(side: &PinSide) -> String {
+    match side.range.as_ref().map(|range| (range.start, range.end)) {
+        Some((Some(start), Some(end))) if start == end => format!("{}[{}]", side.name, start),
+        Some((Some(start), Some(end))) => format!("{}[{}..{}]", side.name, start, end),
+        _ => side.name.clone(),
+    }
+}
+
 impl std::error::Error for WireError {}
 
 pub trait ChipInterface: std::fmt::Debug {
@@ -89,6 +128,141 @@ pub trait ChipInterface: std::fmt::Debug {
     fn is_output_pin(&self, name: &str) -> bool;
     fn eval(&mut self) -> Result<()>;
     fn reset(&mut self) -> Result<()>;
+    
+    /// Build a new, zero-state instance with the same structure as this chip
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>>;
+    
+    /// Access the clocked behaviour of a sequential chip (None for combinational chips)
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        None
+    }
+    
+    /// Hash of the state only the clock may change (None for combinational chips)
+    /// Clocked chips must keep `eval` read-only; debug builds check this hash around eval
+    fn state_hash(&self) -> Option<u64> {
+        None
+    }
+    
+    /// Drive a pin with `value` in one call
+    fn set_pin(&self, name: &str, value: u16) -> Result<()> {
+        self.get_pin(name)?.borrow_mut().set_bus_voltage(value);
+        Ok(())
+    }
+    
+    /// Read a pin's bus value in one call
+    fn read_pin(&self, name: &str) -> Result<u16> {
+        Ok(self.get_pin(name)?.borrow().bus_voltage())
+    }
+    
+    /// Declared width of a pin, without handing the caller a borrow
+    fn pin_width(&self, name: &str) -> Result<usize> {
+        Ok(self.get_pin(name)?.borrow().width())
+    }
+    
+    /// Eval until an eval changes no output or internal pin, failing after `max_evals` evals
+    /// Returns the number of evals run, counting the final one that changed nothing
+    fn eval_settle(&mut self, max_evals: usize) -> Result<usize> {
+        let snapshot = |chip: &Self| -> Vec<(usize, u16)> {
+            chip.output_pins().values()
+                .chain(chip.internal_pins().values())
+                .map(|pin| pin.borrow().fingerprint())
+                .collect()
+        };
+        
+        let mut previous = snapshot(self);
+        for evals in 1..=max_evals {
+            self.eval()?;
+            let current = snapshot(self);
+            if current == previous {
+                return Ok(evals);
+            }
+            previous = current;
+        }
+        
+        Err(SimulatorError::Hardware(format!(
+            "Chip '{}' did not settle within {} evals", self.name(), max_evals
+        )))
+    }
+    
+    /// Eval, returning the names (sorted) of the output pins whose value changed
+    /// Lets a front-end skip redrawing when nothing it displays moved
+    fn eval_report(&mut self) -> Result<Vec<String>> {
+        let names = self.output_pin_names_sorted();
+        let snapshot = |chip: &Self| -> Vec<(usize, u16)> {
+            names.iter().map(|name| chip.output_pins()[name].borrow().fingerprint()).collect()
+        };
+        
+        let before = snapshot(self);
+        self.eval()?;
+        let after = snapshot(self);
+        
+        Ok(names.iter().zip(before.iter().zip(&after))
+            .filter(|(_, (before, after))| before != after)
+            .map(|(name, _)| name.clone())
+            .collect())
+    }
+    
+    /// True if outputs depend only on current inputs, so an eval with unchanged inputs can be skipped
+    /// Defaults to false; only chips with no internal or externally-set state may opt in
+    fn is_combinational(&self) -> bool {
+        false
+    }
+    
+    /// Input pin names in sorted order, stable across builds unlike `input_pins()` iteration
+    fn input_pin_names_sorted(&self) -> Vec<String> {
+        sorted_pin_names(self.input_pins())
+    }
+    
+    /// Output pin names in sorted order
+    fn output_pin_names_sorted(&self) -> Vec<String> {
+        sorted_pin_names(self.output_pins())
+    }
+    
+    /// Internal pin names in sorted order
+    fn internal_pin_names_sorted(&self) -> Vec<String> {
+        sorted_pin_names(self.internal_pins())
+    }
+}
+
+fn sorted_pin_names(pins: &HashMap<String, Rc<RefCell<dyn Pin>>>) -> Vec<String> {
+    let mut names: Vec<String> = pins.keys().cloned().collect();
+    names.sort();
+    names
+}
+
+/// Calls a composite made on its direct sub-chips, collected while profiling is on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChipStats {
+    pub evals: usize,
+    pub ticks: usize,
+    pub tocks: usize,
+}
+
+/// Snapshot of a chip's pin values for logging, sorted by pin name
+/// A pin that is borrowed elsewhere at the time (e.g. mid-propagation) reads as None
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugState {
+    pub name: String,
+    pub inputs: Vec<(String, Option<u16>)>,
+    pub outputs: Vec<(String, Option<u16>)>,
+    pub internals: Vec<(String, Option<u16>)>,
+    pub sub_chips: usize,
+}
+
+impl DebugState {
+    /// Whether every pin could be read
+    pub fn is_complete(&self) -> bool {
+        self.inputs.iter().chain(&self.outputs).chain(&self.internals).all(|(_, value)| value.is_some())
+    }
+}
+
+// Read each pin without panicking on one that is already mutably borrowed
+fn try_read_pins(pins: &HashMap<String, Rc<RefCell<dyn Pin>>>) -> Vec<(String, Option<u16>)> {
+    let mut values: Vec<_> = pins.iter()
+        .map(|(name, pin)| (name.clone(), pin.try_borrow().ok().map(|pin| pin.bus_voltage())))
+        .collect();
+    values.sort_by(|a, b| a.0.cmp(&b.0));
+    values
 }
 
 pub struct Chip {
@@ -96,10 +270,29 @@ pub struct Chip {
     input_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     output_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
     internal_pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+    // Extra names for pins above, alias -> pin name; not listed in the pin maps
+    aliases: HashMap<String, String>,
     sub_chips: Vec<Box<dyn ChipInterface>>,
-    clock_receiver: Option<broadcast::Receiver<ClockTick>>,
+    // Indices of the sub-chips that receive clock edges
+    clocked_parts: Vec<usize>,
+    // Connections each sub-chip was wired with, kept so `fresh` can rebuild the netlist
+    part_connections: Vec<Vec<Connection>>,
+    clock_receiver: Option<ClockReceiver>,
     // Track SubBus instances for propagation
     subbus_connections: Vec<Rc<RefCell<dyn Pin>>>,
+    // Connection each tracked SubBus was made for, e.g. "out=wide[0..7]"; parallel to subbus_connections
+    subbus_origins: Vec<String>,
+    // Host pin each tracked SubBus slices; parallel to subbus_connections
+    subbus_hosts: Vec<String>,
+    // Input values re-applied by reset instead of zeroing
+    latched_inputs: HashMap<String, u16>,
+    // Skip combinational parts whose inputs are unchanged since their last eval
+    incremental: bool,
+    // Per sub-chip input values at its last eval (None = must eval)
+    input_snapshots: Vec<Option<Vec<(usize, u16)>>>,
+    // Call counting, on by default only in debug builds
+    profiling: bool,
+    stats: ChipStats,
 }
 
 impl Chip {
@@ -109,9 +302,19 @@ impl Chip {
             input_pins: HashMap::new(),
             output_pins: HashMap::new(),
             internal_pins: HashMap::new(),
+            aliases: HashMap::new(),
             sub_chips: Vec::new(),
+            clocked_parts: Vec::new(),
+            part_connections: Vec::new(),
             clock_receiver: None,
             subbus_connections: Vec::new(),
+            subbus_origins: Vec::new(),
+            subbus_hosts: Vec::new(),
+            latched_inputs: HashMap::new(),
+            incremental: true,
+            input_snapshots: Vec::new(),
+            profiling: cfg!(debug_assertions),
+            stats: ChipStats::default(),
         }
     }
     
@@ -127,8 +330,32 @@ impl Chip {
         self.internal_pins.insert(name, pin);
     }
     
-    pub fn add_sub_chip(&mut self, chip: Box<dyn ChipInterface>) {
+    /// Make `alias` another name for the pin `existing`: both names reach the same bus
+    /// The alias is not added to the pin maps, so listings and snapshots see the pin once
+    pub fn alias_pin(&mut self, existing: &str, alias: &str) -> Result<()> {
+        let target = self.aliases.get(existing).cloned().unwrap_or_else(|| existing.to_string());
+        self.get_pin(&target)?;
+        if self.get_pin(alias).is_ok() {
+            return Err(SimulatorError::Hardware(
+                format!("Cannot alias '{}' as '{}': chip '{}' already has a pin named '{}'", existing, alias, self.name, alias)
+            ));
+        }
+        self.aliases.insert(alias.to_string(), target);
+        Ok(())
+    }
+    
+    // The pin name an alias stands for, or the name itself
+    fn resolve_alias<'a>(&'a self, name: &'a str) -> &'a str {
+        self.aliases.get(name).map_or(name, String::as_str)
+    }
+    
+    pub fn add_sub_chip(&mut self, mut chip: Box<dyn ChipInterface>) {
+        if chip.as_clocked_mut().is_some() {
+            self.clocked_parts.push(self.sub_chips.len());
+        }
         self.sub_chips.push(chip);
+        self.part_connections.push(Vec::new());
+        self.input_snapshots.push(None);
     }
     
     pub fn connect_pins(&mut self, from_pin: &str, to_pin: &str) -> Result<()> {
@@ -142,25 +369,234 @@ impl Chip {
         Ok(())
     }
     
-    pub fn subscribe_to_clock(&mut self, receiver: broadcast::Receiver<ClockTick>) {
+    /// Look up a pin, falling back to a case-insensitive match when the exact name is absent
+    /// Fails if no pin matches or several pins differ only by case
+    pub fn get_pin_ci(&self, name: &str) -> Result<Rc<RefCell<dyn Pin>>> {
+        if let Ok(pin) = self.get_pin(name) {
+            return Ok(pin);
+        }
+        
+        let mut matches: Vec<(&String, &Rc<RefCell<dyn Pin>>)> = self.input_pins.iter()
+            .chain(self.output_pins.iter())
+            .chain(self.internal_pins.iter())
+            .filter(|(pin_name, _)| pin_name.eq_ignore_ascii_case(name))
+            .collect();
+        
+        match matches.len() {
+            0 => self.get_pin(name),
+            1 => Ok(matches[0].1.clone()),
+            _ => {
+                matches.sort_by(|a, b| a.0.cmp(b.0));
+                let candidates: Vec<&str> = matches.iter().map(|(pin_name, _)| pin_name.as_str()).collect();
+                Err(SimulatorError::Hardware(format!(
+                    "Pin '{}' is ambiguous in chip '{}': matches {}",
+                    name, self.name, candidates.join(", ")
+                )))
+            }
+        }
+    }
+    
+    /// Choose between incremental eval (the default), which skips combinational parts whose
+    /// inputs haven't changed, and full eval of every part
+    pub fn set_incremental(&mut self, incremental: bool) {
+        self.incremental = incremental;
+        self.input_snapshots.fill(None);
+    }
+    
+    /// Turn call counting on or off; it defaults to on in debug builds and off in release
+    pub fn set_profiling(&mut self, profiling: bool) {
+        self.profiling = profiling;
+    }
+    
+    /// Pin values for logging that never panics on a pin borrowed mid-eval: such pins read
+    /// as None, and the whole state is None when not a single pin could be read
+    pub fn try_debug_state(&self) -> Option<DebugState> {
+        let state = DebugState {
+            name: self.name.clone(),
+            inputs: try_read_pins(&self.input_pins),
+            outputs: try_read_pins(&self.output_pins),
+            internals: try_read_pins(&self.internal_pins),
+            sub_chips: self.sub_chips.len(),
+        };
+        let mut pins = state.inputs.iter().chain(&state.outputs).chain(&state.internals).peekable();
+        if pins.peek().is_some() && pins.all(|(_, value)| value.is_none()) {
+            return None;
+        }
+        Some(state)
+    }
+    
+    /// One line per SubBus made while wiring, in wiring order, naming the connection it came
+    /// from, e.g. "SubBus wide[0..7] from connection out=wide[0..7]"
+    pub fn subbus_listing(&self) -> Vec<String> {
+        self.subbus_connections.iter().zip(&self.subbus_origins)
+            .map(|(subbus, origin)| format!("SubBus {} from connection {}", subbus.borrow().name(), origin))
+            .collect()
+    }
+    
+    /// Sub-chip eval, tick and tock calls counted so far
+    pub fn stats(&self) -> ChipStats {
+        self.stats
+    }
+    
+    pub fn reset_stats(&mut self) {
+        self.stats = ChipStats::default();
+    }
+    
+    /// Number of sub-chip evals performed so far (while profiling)
+    pub fn sub_chip_evals(&self) -> usize {
+        self.stats.evals
+    }
+    
+    /// Drive an input pin and keep it at `value` across resets
+    pub fn latch_input(&mut self, name: &str, value: u16) -> Result<()> {
+        let pin = self.input_pins.get(name).ok_or_else(|| SimulatorError::PinNotFound {
+            pin: name.to_string(),
+            chip: self.name.clone(),
+        })?;
+        pin.borrow_mut().set_bus_voltage(value);
+        self.latched_inputs.insert(name.to_string(), value);
+        Ok(())
+    }
+    
+    /// Release a latched input so reset zeroes it again
+    pub fn unlatch_input(&mut self, name: &str) {
+        self.latched_inputs.remove(name);
+    }
+    
+    /// Clear the contents of every memory inside this chip, leaving pins, wiring and
+    /// the remaining state alone; outputs reflect the cleared memory after the next eval
+    pub fn clear_memory(&mut self) {
+        for &part in &self.clocked_parts {
+            if let Some(clocked) = self.sub_chips[part].as_clocked_mut() {
+                clocked.clear_state();
+            }
+        }
+    }
+    
+    /// Indices, in wiring order, of the sub-chips this chip forwards clock edges to
+    pub fn clocked_parts(&self) -> &[usize] {
+        &self.clocked_parts
+    }
+    
+    pub fn subscribe_to_clock(&mut self, receiver: ClockReceiver) {
         self.clock_receiver = Some(receiver);
     }
     
+    /// Replay clock edges published since the last eval onto the clocked sub-chips
+    /// Rising edges settle the combinational inputs first so registers sample current values
+    fn drain_clock(&mut self) -> Result<()> {
+        let mut edges = Vec::new();
+        if let Some(receiver) = self.clock_receiver.as_mut() {
+            while let Some(tick) = receiver.try_recv() {
+                edges.push(tick);
+            }
+        }
+        
+        for edge in edges {
+            // Clock::reset publishes tick 0, which is not an edge
+            if edge.ticks == 0 {
+                continue;
+            }
+            
+            if edge.level == HIGH {
+                ClockedChip::tick(self, edge.level)?;
+            } else {
+                ClockedChip::tock(self, edge.level)?;
+            }
+        }
+        
+        Ok(())
+    }
+    
+    // Settle the combinational network without consuming clock edges
+    fn eval_parts(&mut self) -> Result<()> {
+        // First, propagate signals through SubBus connections
+        self.propagate_subbus_signals()?;
+        
+        // Then evaluate all sub-chips in dependency order; a skipped part keeps driving
+        // the outputs it computed from identical inputs, and any part fed by an output
+        // that did change sees different inputs and is evaluated
+        let parts = self.sub_chips.iter_mut().zip(&mut self.input_snapshots).zip(&self.part_connections);
+        for ((sub_chip, snapshot), connections) in parts {
+            if self.incremental && sub_chip.is_combinational() {
+                let inputs: Vec<(usize, u16)> = sub_chip.input_pins().values()
+                    .map(|pin| pin.borrow().fingerprint())
+                    .collect();
+                if snapshot.as_ref() == Some(&inputs) {
+                    continue;
+                }
+                *snapshot = Some(inputs);
+            }
+            
+            #[cfg(debug_assertions)]
+            let state_before = sub_chip.state_hash();
+            
+            sub_chip.eval()?;
+            
+            #[cfg(debug_assertions)]
+            debug_assert_eq!(
+                state_before,
+                sub_chip.state_hash(),
+                "eval of clocked chip '{}' changed its internal state",
+                sub_chip.name()
+            );
+            if self.profiling {
+                self.stats.evals += 1;
+            }
+            
+            // Slices of the host pins this part drives are read by later parts in the same pass
+            let driven: Vec<&str> = connections.iter()
+                .filter(|connection| sub_chip.is_output_pin(&connection.to.name))
+                .map(|connection| connection.from.name.as_str())
+                .collect();
+            let subbuses = self.subbus_connections.iter().zip(&self.subbus_hosts)
+                .filter(|(_, host)| driven.contains(&host.as_str()))
+                .map(|(subbus, _)| subbus);
+            propagate_subbuses(subbuses)?;
+        }
+        
+        // Finally, propagate any output signals back through SubBus connections
+        self.propagate_subbus_signals()?;
+        
+        Ok(())
+    }
+    
     /// Propagate signals through all SubBus connections
     fn propagate_subbus_signals(&mut self) -> Result<()> {
-        // Force all tracked SubBus instances to propagate their current values
-        for subbus in &self.subbus_connections {
-            if let Ok(mut subbus_pin) = subbus.try_borrow_mut() {
-                // Trigger propagation by re-setting the current bus voltage
-                let current_voltage = subbus_pin.bus_voltage();
-                subbus_pin.set_bus_voltage(current_voltage);
+        propagate_subbuses(&self.subbus_connections)
+    }
+    
+    // Part resets clear every part input, so drive the constant ones again from the wiring
+    fn drive_constants(&self) -> Result<()> {
+        for (sub_chip, connections) in self.sub_chips.iter().zip(&self.part_connections) {
+            let constants = connections.iter()
+                .filter(|connection| is_constant_side(&connection.from) && sub_chip.is_input_pin(&connection.to.name));
+            for connection in constants {
+                let to_pin = sub_chip.get_pin(&connection.to.name)?;
+                let target = match &connection.to.range {
+                    Some(range) => create_input_subbus(to_pin, range)?,
+                    None => to_pin,
+                };
+                let width = target.borrow().width();
+                target.borrow_mut().set_bus_voltage(constant_value(&connection.from, width));
             }
         }
         Ok(())
     }
     
     /// Wire a part chip to this chip with the given connections
-    pub fn wire(&mut self, part: Box<dyn ChipInterface>, connections: Vec<Connection>) -> std::result::Result<(), WireError> {
+    pub fn wire(&mut self, mut part: Box<dyn ChipInterface>, connections: Vec<Connection>) -> std::result::Result<(), WireError> {
+        let clocked = part.as_clocked_mut().is_some();
+        self.wire_part(part, connections, clocked)
+    }
+    
+    /// `wire`, with the caller deciding whether the part is registered for clock edges
+    pub fn wire_part(
+        &mut self,
+        part: Box<dyn ChipInterface>,
+        connections: Vec<Connection>,
+        clocked: bool,
+    ) -> std::result::Result<(), WireError> {
         // Validate all connections first
         for connection in &connections {
             self.validate_connection(part.as_ref(), connection)?;
@@ -172,11 +608,70 @@ impl Chip {
         }
         
         // Add the part to our sub-chips
+        if clocked {
+            self.clocked_parts.push(self.sub_chips.len());
+        }
         self.sub_chips.push(part);
+        self.part_connections.push(connections);
+        self.input_snapshots.push(None);
         
         Ok(())
     }
     
+    /// `wire` for callers working in `crate::Result`, so failures flow through `?`
+    pub fn wire_checked(&mut self, part: Box<dyn ChipInterface>, connections: Vec<Connection>) -> Result<()> {
+        Ok(self.wire(part, connections)?)
+    }
+    
+    /// Reject feedback that never passes through a clocked part
+    /// Clocked parts are sources in the dependency graph: their outputs only change on a clock
+    /// edge, so a loop through a DFF or register is legal and eval never iterates across it
+    pub fn check_combinational_loops(&self) -> std::result::Result<(), WireError> {
+        let dependencies = self.part_dependencies();
+        // 0 = unvisited, 1 = on the search path, 2 = finished
+        let mut state = vec![0u8; self.sub_chips.len()];
+        let mut path = Vec::new();
+        for part in 0..self.sub_chips.len() {
+            if let Some(mut cycle) = find_cycle(part, &dependencies, &mut state, &mut path) {
+                // Dependencies point from reader to driver; report in signal-flow order
+                cycle.reverse();
+                return Err(WireError::CircularDependency {
+                    cycle: cycle.iter().map(|&part| self.sub_chips[part].name().to_string()).collect(),
+                });
+            }
+        }
+        Ok(())
+    }
+    
+    // For each part, the parts whose outputs drive host bits it reads
+    fn part_dependencies(&self) -> Vec<Vec<usize>> {
+        let parts = || self.sub_chips.iter().zip(&self.part_connections);
+        
+        // (host pin, bits, driving part) for every part output
+        let mut drivers: Vec<(&str, u64, usize)> = Vec::new();
+        for (part, (sub_chip, connections)) in parts().enumerate() {
+            for connection in connections.iter().filter(|c| sub_chip.is_output_pin(&c.to.name)) {
+                drivers.push((&connection.from.name, range_mask(&connection.from.range), part));
+            }
+        }
+        
+        parts().map(|(sub_chip, connections)| {
+            let mut dependencies = Vec::new();
+            if !sub_chip.is_combinational() {
+                return dependencies;
+            }
+            for connection in connections.iter().filter(|c| sub_chip.is_input_pin(&c.to.name)) {
+                let bits = range_mask(&connection.from.range);
+                for &(name, driven, driver) in &drivers {
+                    if name == connection.from.name && driven & bits != 0 && !dependencies.contains(&driver) {
+                        dependencies.push(driver);
+                    }
+                }
+            }
+            dependencies
+        }).collect()
+    }
+    
     /// Validate a single connection
     fn validate_connection(&self, part: &dyn ChipInterface, connection: &Connection) -> std::result::Result<(), WireError> {
         // Check if the part pin is an input or output to determine connection direction
@@ -208,6 +703,25 @@ impl Chip {
                 chip_name: part.name().to_string(),
             })?;
         
+        // true/false take the width of whatever they drive; a numeric literal is zero-extended
+        // to it but must not be wider
+        if is_constant_side(&connection.from) {
+            let to_width = match &connection.to.range {
+                Some(range) => range.width(),
+                None => to_pin.borrow().width(),
+            };
+            return match parse_constant_literal(&connection.from.name) {
+                Some((_, from_width)) if from_width > to_width => Err(WireError::WidthMismatch {
+                    from_pin: describe_side(&connection.from),
+                    from_width,
+                    to_pin: describe_side(&connection.to),
+                    to_width,
+                    connection: format!("{}={}", connection.to.name, connection.from.name),
+                }),
+                _ => Ok(()),
+            };
+        }
+        
         // Calculate effective widths considering ranges
         let from_width = if let Some(range) = &connection.from.range {
             range.width()
@@ -224,7 +738,9 @@ impl Chip {
         // Check width compatibility
         if from_width != to_width {
             return Err(WireError::WidthMismatch {
+                from_pin: describe_side(&connection.from),
                 from_width,
+                to_pin: describe_side(&connection.to),
                 to_width,
                 connection: format!("{}={}", connection.to.name, connection.from.name),
             });
@@ -255,11 +771,13 @@ impl Chip {
             to_pin.borrow().width()
         };
         
-        // Check width compatibility
+        // Check width compatibility; the error reports the host side first
         if from_width != to_width {
             return Err(WireError::WidthMismatch {
-                from_width,
-                to_width,
+                from_pin: describe_side(&connection.from),
+                from_width: to_width,
+                to_pin: describe_side(&connection.to),
+                to_width: from_width,
                 connection: format!("{}={}", connection.to.name, connection.from.name),
             });
         }
@@ -291,13 +809,25 @@ impl Chip {
     
     /// Make connection to part's input pin (host chip -> part)
     fn make_input_connection(&mut self, part: &dyn ChipInterface, connection: &Connection) -> std::result::Result<(), WireError> {
-        let from_pin = self.resolve_pin_side(&connection.from, "from")?;
         let to_pin = part.get_pin(&connection.to.name)
             .map_err(|_| WireError::PinNotFound {
                 pin_name: connection.to.name.clone(),
                 chip_name: part.name().to_string(),
             })?;
         
+        let from_pin = if is_constant_side(&connection.from) {
+            // Size the constant to the part pin (or its range) so `true` fills every bit
+            let width = match &connection.to.range {
+                Some(range) => range.width(),
+                None => to_pin.borrow().width(),
+            };
+            let constant_pin = ConstantPin::with_value(connection.from.name.clone(), constant_value(&connection.from, width), width)
+                .map_err(|e| WireError::InvalidRange { pin_name: connection.from.name.clone(), error: e.to_string() })?;
+            Rc::new(RefCell::new(constant_pin)) as Rc<RefCell<dyn Pin>>
+        } else {
+            self.resolve_pin_side(&connection.from, "from")?
+        };
+        
         // Create SubBus wrappers if needed
         let effective_from_pin = if let Some(range) = &connection.from.range {
             let subbus = create_output_subbus(from_pin, range)
@@ -306,7 +836,7 @@ impl Chip {
                     error: e.to_string(),
                 })?;
             // Track the SubBus for propagation
-            self.subbus_connections.push(subbus.clone());
+            self.track_subbus(&subbus, connection);
             subbus
         } else {
             from_pin
@@ -319,7 +849,7 @@ impl Chip {
                     error: e.to_string(),
                 })?;
             // Track the SubBus for propagation
-            self.subbus_connections.push(subbus.clone());
+            self.track_subbus(&subbus, connection);
             subbus
         } else {
             to_pin
@@ -349,7 +879,7 @@ impl Chip {
                     error: e.to_string(),
                 })?;
             // Track the SubBus for propagation
-            self.subbus_connections.push(subbus.clone());
+            self.track_subbus(&subbus, connection);
             subbus
         } else {
             from_pin
@@ -362,7 +892,7 @@ impl Chip {
                     error: e.to_string(),
                 })?;
             // Track the SubBus for propagation
-            self.subbus_connections.push(subbus.clone());
+            self.track_subbus(&subbus, connection);
             subbus
         } else {
             to_pin
@@ -375,24 +905,30 @@ impl Chip {
         Ok(())
     }
     
+    // Track a SubBus for propagation, remembering the connection it came from for subbus_listing
+    fn track_subbus(&mut self, subbus: &Rc<RefCell<dyn Pin>>, connection: &Connection) {
+        self.subbus_connections.push(subbus.clone());
+        self.subbus_hosts.push(connection.from.name.clone());
+        self.subbus_origins.push(format!("{}={}", describe_side(&connection.to), describe_side(&connection.from)));
+    }
+    
     /// Resolve a pin side to an actual pin, handling constants
     fn resolve_pin_side(&self, pin_side: &PinSide, _context: &str) -> std::result::Result<Rc<RefCell<dyn Pin>>, WireError> {
         match pin_side.name.as_str() {
-            "true" => {
-                // Create a constant HIGH pin
-                use crate::chip::Bus;
-                let constant_pin = Rc::new(RefCell::new(Bus::new("true".to_string(), 1)));
-                constant_pin.borrow_mut().set_bus_voltage(1);
-                Ok(constant_pin as Rc<RefCell<dyn Pin>>)
-            }
-            "false" => {
-                // Create a constant LOW pin
-                use crate::chip::Bus;
-                let constant_pin = Rc::new(RefCell::new(Bus::new("false".to_string(), 1)));
-                constant_pin.borrow_mut().set_bus_voltage(0);
-                Ok(constant_pin as Rc<RefCell<dyn Pin>>)
+            "true" | "false" => {
+                // A read-only 1-bit constant
+                let constant_pin = ConstantPin::new(pin_side.name.clone())
+                    .map_err(|e| WireError::InvalidRange { pin_name: pin_side.name.clone(), error: e.to_string() })?;
+                Ok(Rc::new(RefCell::new(constant_pin)) as Rc<RefCell<dyn Pin>>)
             }
-            _ => {
+            name => {
+                if let Some((value, width)) = parse_constant_literal(name) {
+                    // A read-only constant holding the literal
+                    let constant_pin = ConstantPin::with_value(name.to_string(), value, width)
+                        .map_err(|e| WireError::InvalidRange { pin_name: name.to_string(), error: e.to_string() })?;
+                    return Ok(Rc::new(RefCell::new(constant_pin)) as Rc<RefCell<dyn Pin>>);
+                }
+                
                 self.get_pin(&pin_side.name)
                     .map_err(|_| WireError::PinNotFound {
                         pin_name: pin_side.name.clone(),
@@ -403,6 +939,69 @@ impl Chip {
     }
 }
 
+// Force the given SubBus instances to propagate their current values
+fn propagate_subbuses<'a>(subbuses: impl IntoIterator<Item = &'a Rc<RefCell<dyn Pin>>>) -> Result<()> {
+    for subbus in subbuses {
+        if let Ok(mut subbus_pin) = subbus.try_borrow_mut() {
+            // Trigger propagation by re-setting the current bus voltage
+            let current_voltage = subbus_pin.bus_voltage();
+            subbus_pin.set_bus_voltage(current_voltage);
+            subbus_pin.flush_deferred()?;
+        }
+    }
+    Ok(())
+}
+
+// Bits of a host pin covered by an optional range; an unranged side covers them all
+fn range_mask(range: &Option<PinRange>) -> u64 {
+    match range {
+        Some(range) if !range.is_full_pin() => {
+            (range.start_index()..=range.end_index()).fold(0, |mask, bit| mask | 1 << bit)
+        }
+        _ => u64::MAX,
+    }
+}
+
+// Depth-first search along dependencies for a cycle reachable from `part`
+fn find_cycle(part: usize, dependencies: &[Vec<usize>], state: &mut [u8], path: &mut Vec<usize>) -> Option<Vec<usize>> {
+    match state[part] {
+        1 => {
+            let start = path.iter().position(|&p| p == part).expect("part is on the search path");
+            let mut cycle = path[start..].to_vec();
+            cycle.push(part);
+            return Some(cycle);
+        }
+        2 => return None,
+        _ => {}
+    }
+    
+    state[part] = 1;
+    path.push(part);
+    for &dependency in &dependencies[part] {
+        if let Some(cycle) = find_cycle(dependency, dependencies, state, path) {
+            return Some(cycle);
+        }
+    }
+    path.pop();
+    state[part] = 2;
+    None
+}
+
+// What a constant host side drives into `width` bits: `true` fills them all, a numeric
+// literal is zero-extended and `false` is zero
+fn constant_value(pin_side: &PinSide, width: usize) -> u16 {
+    match pin_side.name.as_str() {
+        "true" => ((1u32 << width) - 1) as u16,
+        name => parse_constant_literal(name).map_or(0, |(value, _)| value),
+    }
+}
+
+// Unranged true/false or numeric literal on the host side of a connection
+fn is_constant_side(pin_side: &PinSide) -> bool {
+    pin_side.range.is_none()
+        && (matches!(pin_side.name.as_str(), "true" | "false") || parse_constant_literal(&pin_side.name).is_some())
+}
+
 impl ChipInterface for Chip {
     fn name(&self) -> &str {
         &self.name
@@ -433,40 +1032,100 @@ impl ChipInterface for Chip {
             return Ok(pin.clone());
         }
         
+        if let Some(target) = self.aliases.get(name) {
+            return self.get_pin(target);
+        }
+        
         Err(SimulatorError::Hardware(
             format!("Pin '{}' not found in chip '{}'", name, self.name)
         ))
     }
     
     fn is_input_pin(&self, name: &str) -> bool {
-        self.input_pins.contains_key(name)
+        self.input_pins.contains_key(self.resolve_alias(name))
     }
     
     fn is_output_pin(&self, name: &str) -> bool {
-        self.output_pins.contains_key(name)
+        self.output_pins.contains_key(self.resolve_alias(name))
     }
     
     fn eval(&mut self) -> Result<()> {
-        // First, propagate signals through SubBus connections
-        self.propagate_subbus_signals()?;
+        // Apply any clock edges received since the last eval, then settle; failures carry
+        // the chip path so errors from deep in the hierarchy can be traced
+        self.drain_clock()
+            .and_then(|_| self.eval_parts())
+            .map_err(|e| e.in_chip(&self.name))
+    }
+    
+    fn as_clocked_mut(&mut self) -> Option<&mut dyn ClockedChip> {
+        // Only composites holding clocked parts have edges to forward
+        if !self.clocked_parts.is_empty() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+    
+    fn is_combinational(&self) -> bool {
+        self.clock_receiver.is_none() && self.sub_chips.iter().all(|sub_chip| sub_chip.is_combinational())
+    }
+    
+    fn fresh(&self) -> Result<Box<dyn ChipInterface>> {
+        // Pins can't be shared between copies, so rebuild the netlist from the recorded wiring
+        // (pins joined ad hoc through `connect_pins` are not recorded and are not reproduced)
+        let mut chip = Chip::new(self.name.clone());
+        chip.incremental = self.incremental;
+        chip.profiling = self.profiling;
+        for (name, pin) in &self.input_pins {
+            let width = pin.borrow().width();
+            chip.add_input_pin(name.clone(), Rc::new(RefCell::new(Bus::new(name.clone(), width))));
+        }
+        for (name, pin) in &self.output_pins {
+            let width = pin.borrow().width();
+            chip.add_output_pin(name.clone(), Rc::new(RefCell::new(Bus::new(name.clone(), width))));
+        }
+        for (name, pin) in &self.internal_pins {
+            let width = pin.borrow().width();
+            chip.add_internal_pin(name.clone(), Rc::new(RefCell::new(Bus::new(name.clone(), width))));
+        }
+        chip.aliases = self.aliases.clone();
         
-        // Then evaluate all sub-chips in dependency order
-        for sub_chip in &mut self.sub_chips {
-            sub_chip.eval()?;
+        for (index, (sub_chip, connections)) in self.sub_chips.iter().zip(&self.part_connections).enumerate() {
+            let part = sub_chip.fresh()?;
+            if connections.is_empty() {
+                chip.add_sub_chip(part);
+            } else {
+                let clocked = self.clocked_parts.contains(&index);
+                chip.wire_part(part, connections.clone(), clocked).map_err(|e| SimulatorError::Hardware(
+                    format!("Failed to rewire part {} of {}: {}", sub_chip.name(), self.name, e)
+                ))?;
+            }
         }
         
-        // Finally, propagate any output signals back through SubBus connections
-        self.propagate_subbus_signals()?;
+        for (name, value) in &self.latched_inputs {
+            chip.latch_input(name, *value)?;
+        }
+        if let Some(receiver) = &self.clock_receiver {
+            chip.subscribe_to_clock(receiver.resubscribe());
+        }
         
-        Ok(())
+        Ok(Box::new(chip))
     }
     
     fn reset(&mut self) -> Result<()> {
+        // Edges published before the reset no longer apply
+        if let Some(receiver) = self.clock_receiver.as_mut() {
+            *receiver = receiver.resubscribe();
+        }
+        
         // Reset all sub-chips
         for sub_chip in &mut self.sub_chips {
             sub_chip.reset()?;
         }
         
+        // Resets cleared part outputs, so every part must eval again
+        self.input_snapshots.fill(None);
+        
         // Reset all pins to LOW
         for pin in self.input_pins.values() {
             pin.borrow_mut().set_bus_voltage(0);
@@ -480,8 +1139,47 @@ impl ChipInterface for Chip {
             pin.borrow_mut().set_bus_voltage(0);
         }
         
+        // Re-apply latched inputs
+        for (name, value) in &self.latched_inputs {
+            self.input_pins[name].borrow_mut().set_bus_voltage(*value);
+        }
+        
+        self.drive_constants()
+    }
+}
+
+// Edges driven directly (e.g. by the test framework) rather than through a clock subscription
+impl ClockedChip for Chip {
+    fn tick(&mut self, clock_level: Voltage) -> Result<()> {
+        // Settle first so the clocked parts sample current inputs; until tock their
+        // outputs, and everything downstream of them, still show the previous state
+        self.eval_parts()?;
+        for &part in &self.clocked_parts {
+            if let Some(clocked) = self.sub_chips[part].as_clocked_mut() {
+                clocked.tick(clock_level)?;
+                if self.profiling {
+                    self.stats.ticks += 1;
+                }
+            }
+        }
         Ok(())
     }
+    
+    fn tock(&mut self, clock_level: Voltage) -> Result<()> {
+        for &part in &self.clocked_parts {
+            if let Some(clocked) = self.sub_chips[part].as_clocked_mut() {
+                clocked.tock(clock_level)?;
+                if self.profiling {
+                    self.stats.tocks += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+    
+    fn clear_state(&mut self) {
+        self.clear_memory();
+    }
 }
 
 use std::fmt;
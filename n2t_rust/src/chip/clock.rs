@@ -1,6 +1,9 @@
-use tokio::sync::broadcast;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::{Rc, Weak};
+use crate::chip::ChipInterface;
 use crate::chip::pin::{Voltage, HIGH, LOW};
-use crate::error::Result;
+use crate::error::{Result, SimulatorError};
 
 #[derive(Debug, Clone)]
 pub struct ClockTick {
@@ -8,66 +11,368 @@ pub struct ClockTick {
     pub ticks: u64,
 }
 
+// Edges kept per subscriber before the oldest are dropped, matching the broadcast channel capacity
+const RETAINED_EDGES: usize = 1000;
+
+/// Something that advances a clock and distributes its edges to subscribers
+/// `Clock` is the synchronous default; `TokioClock` (feature `tokio`) broadcasts across tasks
+pub trait ClockSource {
+    /// Advance half a cycle, toggling the level and publishing the edge
+    fn tick(&mut self) -> Result<()>;
+    
+    /// Return to LOW at tick 0, publishing tick 0 (which subscribers do not treat as an edge)
+    fn reset(&mut self);
+    
+    fn level(&self) -> Voltage;
+    fn ticks(&self) -> u64;
+    
+    /// Receiver for every edge published from now on
+    fn subscribe(&self) -> ClockReceiver;
+    
+    /// Stop for good: receivers see the edges already published and then report stopped,
+    /// and further ticks fail
+    fn stop(&mut self);
+    
+    fn is_stopped(&self) -> bool;
+}
+
+/// Run one full clock cycle on `chip`: settle, tick on the first edge, tock on the second,
+/// then settle again. A combinational chip is just evaluated. Either way the clock
+/// advances by two edges, so its time counter matches the cycles driven
+pub fn clock_cycle(chip: &mut dyn ChipInterface, clock: &mut dyn ClockSource) -> Result<()> {
+    clock.tick()?;
+    chip.eval()?;
+    if let Some(clocked) = chip.as_clocked_mut() {
+        clocked.tick(clock.level())?;
+    }
+    
+    clock.tick()?;
+    if let Some(clocked) = chip.as_clocked_mut() {
+        clocked.tock(clock.level())?;
+    }
+    chip.eval()
+}
+
+fn stopped_error() -> SimulatorError {
+    SimulatorError::Hardware("Clock has been stopped".to_string())
+}
+
+// Per-subscriber edge queue, and the clock's list of them
+type Mailbox = Rc<RefCell<VecDeque<ClockTick>>>;
+type MailboxList = RefCell<Vec<Weak<RefCell<VecDeque<ClockTick>>>>>;
+type Mailboxes = Rc<MailboxList>;
+
+/// Receiving end of a clock subscription, polled with `try_recv`
+#[derive(Debug)]
+pub struct ClockReceiver {
+    inner: ReceiverInner,
+}
+
+#[derive(Debug)]
+enum ReceiverInner {
+    Local { mailbox: Mailbox, mailboxes: Weak<MailboxList> },
+    #[cfg(feature = "tokio")]
+    Broadcast(tokio::sync::broadcast::Receiver<ClockTick>),
+}
+
+impl ClockReceiver {
+    /// Next pending edge, if any; edges dropped because the subscriber fell behind are skipped
+    pub fn try_recv(&mut self) -> Option<ClockTick> {
+        match &mut self.inner {
+            ReceiverInner::Local { mailbox, .. } => mailbox.borrow_mut().pop_front(),
+            #[cfg(feature = "tokio")]
+            ReceiverInner::Broadcast(receiver) => {
+                use tokio::sync::broadcast::error::TryRecvError;
+                loop {
+                    match receiver.try_recv() {
+                        Ok(tick) => return Some(tick),
+                        // Missed edges cannot be replayed; carry on from the oldest retained one
+                        Err(TryRecvError::Lagged(_)) => continue,
+                        Err(_) => return None,
+                    }
+                }
+            }
+        }
+    }
+    
+    /// True once the clock has stopped and every edge it published has been received
+    pub fn is_stopped(&self) -> bool {
+        match &self.inner {
+            ReceiverInner::Local { mailbox, mailboxes } => {
+                mailbox.borrow().is_empty() && mailboxes.strong_count() == 0
+            }
+            #[cfg(feature = "tokio")]
+            ReceiverInner::Broadcast(receiver) => receiver.is_empty() && receiver.is_closed(),
+        }
+    }
+    
+    /// Wait for the next edge; None once the clock has stopped, so a receive loop
+    /// `while let Some(tick) = receiver.recv().await` ends when the clock is stopped
+    /// A synchronous `Clock` never publishes while the caller waits, so this doesn't block
+    #[cfg(feature = "tokio")]
+    pub async fn recv(&mut self) -> Option<ClockTick> {
+        match &mut self.inner {
+            ReceiverInner::Local { .. } => self.try_recv(),
+            ReceiverInner::Broadcast(receiver) => {
+                use tokio::sync::broadcast::error::RecvError;
+                loop {
+                    match receiver.recv().await {
+                        Ok(tick) => return Some(tick),
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            }
+        }
+    }
+    
+    /// New receiver on the same clock that only sees edges published from now on
+    pub fn resubscribe(&self) -> ClockReceiver {
+        match &self.inner {
+            ReceiverInner::Local { mailboxes, .. } => {
+                let mailbox = Mailbox::default();
+                if let Some(mailboxes) = mailboxes.upgrade() {
+                    mailboxes.borrow_mut().push(Rc::downgrade(&mailbox));
+                }
+                ClockReceiver {
+                    inner: ReceiverInner::Local { mailbox, mailboxes: mailboxes.clone() },
+                }
+            }
+            #[cfg(feature = "tokio")]
+            ReceiverInner::Broadcast(receiver) => ClockReceiver {
+                inner: ReceiverInner::Broadcast(receiver.resubscribe()),
+            },
+        }
+    }
+}
+
+/// Synchronous clock: each subscriber gets its own queue of edges, no async runtime needed
 #[derive(Debug)]
 pub struct Clock {
-    sender: broadcast::Sender<ClockTick>,
+    mailboxes: Mailboxes,
     level: Voltage,
     ticks: u64,
+    stopped: bool,
 }
 
 impl Clock {
     pub fn new() -> Self {
-        let (sender, _) = broadcast::channel(1000);
-        
         Self {
-            sender,
+            mailboxes: Mailboxes::default(),
             level: LOW,
             ticks: 0,
+            stopped: false,
         }
     }
     
-    pub fn subscribe(&self) -> broadcast::Receiver<ClockTick> {
-        self.sender.subscribe()
+    pub fn subscribe(&self) -> ClockReceiver {
+        ClockSource::subscribe(self)
     }
     
     pub fn tick(&mut self) -> Result<()> {
+        ClockSource::tick(self)
+    }
+    
+    pub fn reset(&mut self) {
+        ClockSource::reset(self)
+    }
+    
+    pub fn stop(&mut self) {
+        ClockSource::stop(self)
+    }
+    
+    pub fn level(&self) -> Voltage {
+        self.level
+    }
+    
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+    
+    fn publish(&self, tick: ClockTick) {
+        // Drop mailboxes whose receivers are gone
+        let mut mailboxes = self.mailboxes.borrow_mut();
+        mailboxes.retain(|mailbox| mailbox.strong_count() > 0);
+        
+        for mailbox in mailboxes.iter().filter_map(Weak::upgrade) {
+            let mut queue = mailbox.borrow_mut();
+            if queue.len() == RETAINED_EDGES {
+                queue.pop_front();
+            }
+            queue.push_back(tick.clone());
+        }
+    }
+}
+
+impl ClockSource for Clock {
+    fn tick(&mut self) -> Result<()> {
+        if self.stopped {
+            return Err(stopped_error());
+        }
         self.ticks += 1;
         self.level = if self.level == LOW { HIGH } else { LOW };
         
-        let tick = ClockTick {
+        self.publish(ClockTick {
+            level: self.level,
+            ticks: self.ticks,
+        });
+        
+        Ok(())
+    }
+    
+    fn reset(&mut self) {
+        self.level = LOW;
+        self.ticks = 0;
+        
+        self.publish(ClockTick {
             level: self.level,
             ticks: self.ticks,
+        });
+    }
+    
+    fn level(&self) -> Voltage {
+        self.level
+    }
+    
+    fn ticks(&self) -> u64 {
+        self.ticks
+    }
+    
+    fn subscribe(&self) -> ClockReceiver {
+        let mailbox = Mailbox::default();
+        // Subscribing to a stopped clock yields an already-stopped receiver
+        let mailboxes = if self.stopped {
+            Weak::new()
+        } else {
+            self.mailboxes.borrow_mut().push(Rc::downgrade(&mailbox));
+            Rc::downgrade(&self.mailboxes)
         };
+        ClockReceiver {
+            inner: ReceiverInner::Local { mailbox, mailboxes },
+        }
+    }
+    
+    fn stop(&mut self) {
+        // Receivers hold the list weakly, so replacing it is what tells them
+        self.mailboxes = Mailboxes::default();
+        self.stopped = true;
+    }
+    
+    fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Clock broadcasting its edges over a tokio channel, so it can be driven from another task
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub struct TokioClock {
+    // Dropped on stop, which closes the channel for every receiver
+    sender: Option<tokio::sync::broadcast::Sender<ClockTick>>,
+    level: Voltage,
+    ticks: u64,
+}
+
+#[cfg(feature = "tokio")]
+impl TokioClock {
+    pub fn new() -> Self {
+        let (sender, _) = tokio::sync::broadcast::channel(RETAINED_EDGES);
         
+        Self {
+            sender: Some(sender),
+            level: LOW,
+            ticks: 0,
+        }
+    }
+    
+    fn publish(&self, tick: ClockTick) {
         // Ignore send errors (no active receivers)
-        let _ = self.sender.send(tick);
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(tick);
+        }
+    }
+    
+    /// Tick as fast as subscribers keep up for `duration`, yielding to other tasks after
+    /// every edge; returns the number of full cycles run. The clock keeps running
+    /// afterwards until `stop` is called
+    pub async fn run_for(&mut self, duration: std::time::Duration) -> Result<u64> {
+        let deadline = std::time::Instant::now() + duration;
+        let mut cycles = 0;
+        while std::time::Instant::now() < deadline {
+            for _ in 0..2 {
+                ClockSource::tick(self)?;
+                tokio::task::yield_now().await;
+            }
+            cycles += 1;
+        }
+        Ok(cycles)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ClockSource for TokioClock {
+    fn tick(&mut self) -> Result<()> {
+        if self.sender.is_none() {
+            return Err(stopped_error());
+        }
+        self.ticks += 1;
+        self.level = if self.level == LOW { HIGH } else { LOW };
+        
+        self.publish(ClockTick {
+            level: self.level,
+            ticks: self.ticks,
+        });
         
         Ok(())
     }
     
-    pub fn reset(&mut self) {
+    fn reset(&mut self) {
         self.level = LOW;
         self.ticks = 0;
         
-        let tick = ClockTick {
+        self.publish(ClockTick {
             level: self.level,
             ticks: self.ticks,
-        };
-        
-        let _ = self.sender.send(tick);
+        });
     }
     
-    pub fn level(&self) -> Voltage {
+    fn level(&self) -> Voltage {
         self.level
     }
     
-    pub fn ticks(&self) -> u64 {
+    fn ticks(&self) -> u64 {
         self.ticks
     }
+    
+    fn subscribe(&self) -> ClockReceiver {
+        let receiver = match &self.sender {
+            Some(sender) => sender.subscribe(),
+            // Subscribing to a stopped clock yields an already-closed receiver
+            None => tokio::sync::broadcast::channel(1).1,
+        };
+        ClockReceiver {
+            inner: ReceiverInner::Broadcast(receiver),
+        }
+    }
+    
+    fn stop(&mut self) {
+        self.sender = None;
+    }
+    
+    fn is_stopped(&self) -> bool {
+        self.sender.is_none()
+    }
 }
 
-impl Default for Clock {
+#[cfg(feature = "tokio")]
+impl Default for TokioClock {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
@@ -5,23 +5,31 @@ pub mod pin;
 pub mod builder;
 pub mod builtins;
 pub mod subbus;
+pub mod waveform;
 
 #[cfg(test)]
 mod tests;
 
 pub use bus::Bus;
-pub use chip::{Chip, ChipInterface, Connection, PinSide, WireError};
-pub use pin::{Pin, Voltage, HIGH, LOW};
-pub use builder::ChipBuilder;
-pub use builtins::{ClockedChip, DffChip, BitChip, RegisterChip, PcChip};
-pub use builtins::{Memory, Ram8Chip, Ram64Chip, Ram512Chip, Ram4kChip, Ram16kChip};
-pub use builtins::{Rom32kChip, ScreenChip, KeyboardChip, SCREEN_SIZE, SCREEN_OFFSET, KEYBOARD_OFFSET};
+pub use chip::{Chip, ChipInterface, ChipStats, Connection, DebugState, PinSide, WireError};
+pub use pin::{Pin, Voltage, VoltageExt, HIGH, LOW};
+pub use builder::{ChipBuilder, Diagnostic};
+pub use builtins::{ClockedChip, DffChip, BitChip, RegisterChip, EnabledRegisterChip, RegisterNChip, RegisterFileChip, PcChip, CounterChip};
+pub use builtins::{Memory, Ram8Chip, Ram64Chip, SyncRam64Chip, Ram512Chip, Ram4kChip, Ram16kChip};
+pub use builtins::{Rom32kChip, RamRomChip, ScreenChip, KeyboardChip, DecodeChip, JumpChip, jump_taken, ComputerChip, MemTarget, decode_address, SCREEN_SIZE, SCREEN_OFFSET, KEYBOARD_OFFSET};
 pub use builtins::{NandChip, NotChip, AndChip, OrChip, XorChip};
-pub use builtins::{MuxChip, DMuxChip, DMux4WayChip, DMux8WayChip};
+pub use builtins::{MuxChip, DMuxChip, DMux4WayChip, DMux8WayChip, MuxNWayChip, DMuxNWayChip, TruthTableChip};
+pub use builtins::{BitwiseNChip, BitwiseOp, FloatingCheckChip};
 pub use builtins::{Not16Chip, And16Chip, Or16Chip};
 pub use builtins::{Mux16Chip, Mux4Way16Chip, Mux8Way16Chip};
-pub use builtins::{Add16Chip, Inc16Chip};
-pub use builtins::{HalfAdderChip, FullAdderChip};
-pub use builtins::{AluChip, AluFlags};
-pub use clock::Clock;
+pub use builtins::{Add16Chip, Inc16Chip, IncNChip};
+pub use builtins::{HalfAdderChip, FullAdderChip, HalfSubtractorChip, FullSubtractorChip};
+pub use builtins::{AluChip, AluControl, AluFlags};
+pub use builtins::{OrReduceChip, AndReduceChip};
+pub use builtins::{Equal16Chip, GreaterThan16Chip, Comparator16Chip, ShiftLeft16Chip, ShiftRight16Chip, TriStateBufferChip};
+pub use builtins::{Reverse16Chip, ByteSwap16Chip, PopCount16Chip, PriorityEncoder16Chip, DecoderChip, SignExtendChip};
+pub use clock::{Clock, ClockReceiver, ClockSource, ClockTick, clock_cycle};
+#[cfg(feature = "tokio")]
+pub use clock::TokioClock;
+pub use waveform::WaveformRecorder;
 pub use subbus::{InSubBus, OutSubBus, PinRange, parse_pin_range, create_input_subbus, create_output_subbus};
\ No newline at end of file
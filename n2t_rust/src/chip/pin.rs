@@ -7,6 +7,25 @@ pub const LOW: Voltage = 0;
 
 pub type Voltage = u8;
 
+/// Conversions out of a Voltage; `Voltage` is a `u8` alias, so `From<bool>` already comes from
+/// std and `pin.pull(flag.into(), None)` works as is
+pub trait VoltageExt {
+    /// True for any non-LOW voltage
+    fn is_high(&self) -> bool;
+    /// The voltage as a single bus bit, 0 or 1
+    fn as_bit(&self) -> u16;
+}
+
+impl VoltageExt for Voltage {
+    fn is_high(&self) -> bool {
+        *self != LOW
+    }
+    
+    fn as_bit(&self) -> u16 {
+        self.is_high() as u16
+    }
+}
+
 pub trait Pin: std::fmt::Debug {
     fn name(&self) -> &str;
     fn width(&self) -> usize;
@@ -16,27 +35,89 @@ pub trait Pin: std::fmt::Debug {
     fn toggle(&mut self, bit: Option<usize>) -> Result<()>;
     fn voltage(&self, bit: Option<usize>) -> Result<Voltage>;
     fn connect(&mut self, pin: Weak<RefCell<dyn Pin>>);
+    
+    /// Retry updates skipped because a connected pin was borrowed at the time
+    /// Fails with BorrowConflict if a connected pin is still borrowed
+    fn flush_deferred(&mut self) -> Result<()> {
+        Ok(())
+    }
+    
+    /// True if nothing is driving this pin (high impedance); floating pins read as LOW
+    fn is_floating(&self) -> bool {
+        false
+    }
+    
+    /// Stop driving this pin, leaving it floating
+    fn float(&mut self) {}
+    
+    /// Cheap `(width, value)` snapshot for detecting state changes without cloning the pin
+    fn fingerprint(&self) -> (usize, u16) {
+        (self.width(), self.bus_voltage())
+    }
 }
 
 pub fn is_constant_pin(pin_name: &str) -> bool {
     matches!(pin_name, "false" | "true" | "0" | "1")
 }
 
+/// Parse a numeric HDL literal (`%B1010`, `%X1F`, `%D12` or a bare decimal) into `(value, width)`
+/// Binary and hex literals are as wide as their digits; decimals take as many bits as their value needs
+pub fn parse_constant_literal(text: &str) -> Option<(u16, usize)> {
+    if let Some(digits) = text.strip_prefix("%B") {
+        if digits.is_empty() || digits.len() > 16 {
+            return None;
+        }
+        return u16::from_str_radix(digits, 2).ok().map(|value| (value, digits.len()));
+    }
+    
+    if let Some(digits) = text.strip_prefix("%X") {
+        if digits.is_empty() || digits.len() > 4 {
+            return None;
+        }
+        return u16::from_str_radix(digits, 16).ok().map(|value| (value, digits.len() * 4));
+    }
+    
+    let digits = text.strip_prefix("%D").unwrap_or(text);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let value: u16 = digits.parse().ok()?;
+    Some((value, (16 - value.leading_zeros() as usize).max(1)))
+}
+
 #[derive(Debug)]
 pub struct ConstantPin {
     name: String,
-    voltage: Voltage,
+    value: u16,
+    width: usize,
 }
 
 impl ConstantPin {
     pub fn new(name: String) -> Result<Self> {
-        let voltage = match name.as_str() {
-            "false" | "0" => LOW,
-            "true" | "1" => HIGH,
+        let value = match name.as_str() {
+            "false" | "0" => 0,
+            "true" | "1" => 1,
             _ => return Err(SimulatorError::Hardware(format!("Invalid constant pin name: {}", name))),
         };
         
-        Ok(Self { name, voltage })
+        Ok(Self { name, value, width: 1 })
+    }
+    
+    /// Create a read-only constant spanning `width` bits (all ones for true, all zeros for false)
+    pub fn new_wide(name: String, width: usize) -> Result<Self> {
+        let high = Self::new(name.clone())?.value == 1;
+        Self::with_value(name, if high { u16::MAX } else { 0 }, width)
+    }
+    
+    /// Create a read-only constant holding `value` in `width` bits, e.g. a numeric HDL literal
+    pub fn with_value(name: String, value: u16, width: usize) -> Result<Self> {
+        if width == 0 || width > 16 {
+            return Err(SimulatorError::Hardware(
+                format!("Invalid width {} for constant pin {}", width, name)
+            ));
+        }
+        let mask = ((1u32 << width) - 1) as u16;
+        Ok(Self { name, value: value & mask, width })
     }
 }
 
@@ -46,11 +127,11 @@ impl Pin for ConstantPin {
     }
     
     fn width(&self) -> usize {
-        1
+        self.width
     }
     
     fn bus_voltage(&self) -> u16 {
-        self.voltage as u16
+        self.value
     }
     
     fn set_bus_voltage(&mut self, _voltage: u16) {
@@ -74,10 +155,127 @@ impl Pin for ConstantPin {
                 format!("Bit {} out of bounds for pin {} (width {})", bit, self.name, self.width())
             ));
         }
-        Ok(self.voltage)
+        Ok(((self.value >> bit) & 1) as Voltage)
+    }
+    
+    fn connect(&mut self, pin: Weak<RefCell<dyn Pin>>) {
+        // Nothing changes a constant, so it only needs to drive a pin once, as it is wired
+        if let Some(pin_ref) = pin.upgrade() {
+            if let Ok(mut pin_mut) = pin_ref.try_borrow_mut() {
+                pin_mut.set_bus_voltage(self.value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chip::Bus;
+
+    #[test]
+    fn test_constant_pin_width_one() {
+        let pin = ConstantPin::new("true".to_string()).unwrap();
+        assert_eq!(pin.width(), 1);
+        assert_eq!(pin.bus_voltage(), 1);
+        
+        let pin = ConstantPin::new("false".to_string()).unwrap();
+        assert_eq!(pin.bus_voltage(), 0);
+    }
+    
+    #[test]
+    fn test_wide_constant_pin_is_read_only() {
+        let mut pin = ConstantPin::new_wide("true".to_string(), 16).unwrap();
+        assert_eq!(pin.width(), 16);
+        assert_eq!(pin.bus_voltage(), 0xffff);
+        assert_eq!(pin.voltage(Some(15)).unwrap(), HIGH);
+        
+        // Writes are silently ignored
+        pin.set_bus_voltage(0x1234);
+        pin.pull(LOW, Some(3)).unwrap();
+        pin.toggle(None).unwrap();
+        assert_eq!(pin.bus_voltage(), 0xffff);
+        
+        let pin = ConstantPin::new_wide("false".to_string(), 8).unwrap();
+        assert_eq!(pin.bus_voltage(), 0);
+        assert!(pin.voltage(Some(8)).is_err());
+    }
+    
+    #[test]
+    fn test_wide_constant_pin_partial_width() {
+        let pin = ConstantPin::new_wide("1".to_string(), 4).unwrap();
+        assert_eq!(pin.bus_voltage(), 0x000f);
+        assert!(ConstantPin::new_wide("true".to_string(), 17).is_err());
     }
     
-    fn connect(&mut self, _pin: Weak<RefCell<dyn Pin>>) {
-        // Constants don't need connections
+    #[test]
+    fn test_constant_pin_with_value() {
+        let mut pin = ConstantPin::with_value("%B0101".to_string(), 0b0101, 4).unwrap();
+        assert_eq!(pin.bus_voltage(), 0b0101);
+        assert_eq!((pin.voltage(Some(0)).unwrap(), pin.voltage(Some(1)).unwrap()), (HIGH, LOW));
+        
+        pin.set_bus_voltage(0);
+        assert_eq!(pin.bus_voltage(), 0b0101);
+        
+        // Bits past the width are dropped
+        assert_eq!(ConstantPin::with_value("c".to_string(), 0x1ff, 8).unwrap().bus_voltage(), 0xff);
+        assert!(ConstantPin::with_value("c".to_string(), 1, 0).is_err());
+    }
+    
+    #[test]
+    fn test_bus_fingerprint() {
+        let mut a = Bus::new("a".to_string(), 4);
+        let mut b = Bus::new("b".to_string(), 4);
+        a.set_bus_voltage(0b1010);
+        b.set_bus_voltage(0b1010);
+        
+        // Names don't matter, only width and value
+        assert_eq!(a.fingerprint(), (4, 0b1010));
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_eq!(a, b);
+        
+        b.pull(HIGH, Some(0)).unwrap();
+        assert_ne!(a.fingerprint(), b.fingerprint());
+        assert_ne!(a, b);
+        
+        // Same value at a different width is a different state
+        let mut wide = Bus::new("wide".to_string(), 8);
+        wide.set_bus_voltage(0b1010);
+        assert_ne!(a.fingerprint(), wide.fingerprint());
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_voltage_bool_conversions() {
+        let high: Voltage = true.into();
+        let low: Voltage = false.into();
+        assert_eq!((high, low), (HIGH, LOW));
+        
+        assert!(HIGH.is_high());
+        assert!(!LOW.is_high());
+        assert_eq!((HIGH.as_bit(), LOW.as_bit()), (1, 0));
+        
+        // Round trip through a pin
+        let mut bus = Bus::new("b".to_string(), 2);
+        bus.pull(true.into(), Some(1)).unwrap();
+        assert!(bus.voltage(Some(1)).unwrap().is_high());
+        assert_eq!(bus.voltage(Some(0)).unwrap().as_bit(), 0);
+        assert_eq!(bus.bus_voltage(), 0b10);
+    }
+    
+    #[test]
+    fn test_parse_constant_literal() {
+        assert_eq!(parse_constant_literal("%B1010"), Some((0b1010, 4)));
+        assert_eq!(parse_constant_literal("%B0011"), Some((3, 4)));
+        assert_eq!(parse_constant_literal("%X1F"), Some((0x1f, 8)));
+        assert_eq!(parse_constant_literal("%D12"), Some((12, 4)));
+        assert_eq!(parse_constant_literal("300"), Some((300, 9)));
+        assert_eq!(parse_constant_literal("0"), Some((0, 1)));
+        
+        assert_eq!(parse_constant_literal("%B"), None);
+        assert_eq!(parse_constant_literal("%B102"), None);
+        assert_eq!(parse_constant_literal("%B10101010101010101"), None);
+        assert_eq!(parse_constant_literal("%X12345"), None);
+        assert_eq!(parse_constant_literal("70000"), None);
+        assert_eq!(parse_constant_literal("a1"), None);
+    }
+}
@@ -1,6 +1,7 @@
 // SubBus implementation for pin range operations
 // Supports HDL syntax like a[0..7], a[8..15], a[5], etc.
 
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 use std::cell::RefCell;
 use crate::chip::pin::{Pin, Voltage};
@@ -132,6 +133,8 @@ pub struct OutSubBus {
     start: usize,
     width: usize,
     connections: Vec<std::rc::Weak<RefCell<dyn Pin>>>,
+    // Connected pins that were borrowed when the last value was propagated
+    deferred: Vec<std::rc::Weak<RefCell<dyn Pin>>>,
 }
 
 impl OutSubBus {
@@ -153,6 +156,7 @@ impl OutSubBus {
             start,
             width,
             connections: Vec::new(),
+            deferred: Vec::new(),
         })
     }
     
@@ -161,18 +165,41 @@ impl OutSubBus {
     }
     
     /// Propagate the current SubBus value to all connected pins
+    /// Pins that are borrowed are retried once, then deferred until `flush_deferred`
     fn propagate_to_connections(&mut self, value: u16) {
         // Clean up dead connections first
         self.connections.retain(|weak_pin| weak_pin.strong_count() > 0);
         
-        // Propagate to all connected pins
+        // Propagate to all connected pins, collecting the ones we couldn't borrow
+        let mut skipped = Vec::new();
         for weak_pin in &self.connections {
             if let Some(pin_ref) = weak_pin.upgrade() {
-                if let Ok(mut connected_pin) = pin_ref.try_borrow_mut() {
-                    connected_pin.set_bus_voltage(value);
+                match pin_ref.try_borrow_mut() {
+                    Ok(mut connected_pin) => connected_pin.set_bus_voltage(value),
+                    Err(_) => skipped.push(weak_pin.clone()),
                 }
             }
         }
+        
+        // Second pass: earlier writes may have released the borrow
+        skipped.retain(|weak_pin| match weak_pin.upgrade() {
+            Some(pin_ref) => match pin_ref.try_borrow_mut() {
+                Ok(mut connected_pin) => {
+                    connected_pin.set_bus_voltage(value);
+                    false
+                }
+                Err(_) => true,
+            },
+            None => false,
+        });
+        
+        // A full propagation supersedes anything still pending from the previous one
+        self.deferred = skipped;
+    }
+    
+    /// True if some connected pin missed the latest value
+    pub fn has_deferred(&self) -> bool {
+        !self.deferred.is_empty()
     }
 }
 
@@ -263,11 +290,27 @@ impl Pin for OutSubBus {
         
         self.parent_bus.borrow_mut().toggle(Some(self.start + bit))
     }
+    
+    fn flush_deferred(&mut self) -> Result<()> {
+        let value = self.bus_voltage();
+        while let Some(weak_pin) = self.deferred.pop() {
+            if let Some(pin_ref) = weak_pin.upgrade() {
+                let Ok(mut connected_pin) = pin_ref.try_borrow_mut() else {
+                    let pin = pin_ref.try_borrow().map(|p| p.name().to_string()).unwrap_or_else(|_| self.name.clone());
+                    self.deferred.push(weak_pin);
+                    return Err(SimulatorError::BorrowConflict { pin });
+                };
+                connected_pin.set_bus_voltage(value);
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Parse pin range specification from HDL syntax
 /// Supports: "pin", "pin[5]", "pin[0..7]"
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PinRange {
     pub pin_name: String,
     pub start: Option<usize>,
@@ -305,6 +348,17 @@ impl PinRange {
         })
     }
     
+    /// Range from an inclusive Rust range, e.g. `PinRange::from_rust("a", 0..=7)` for `a[0..7]`
+    /// A reversed range is normalized, as it is in HDL
+    pub fn from_rust(pin_name: impl Into<String>, bits: RangeInclusive<usize>) -> Self {
+        let (start, end) = bits.into_inner();
+        Self {
+            pin_name: pin_name.into(),
+            start: Some(start.min(end)),
+            end: Some(start.max(end)),
+        }
+    }
+    
     /// Get the width of this pin range
     pub fn width(&self) -> usize {
         match (self.start, self.end) {
@@ -335,6 +389,12 @@ impl PinRange {
     }
 }
 
+impl From<(String, RangeInclusive<usize>)> for PinRange {
+    fn from((pin_name, bits): (String, RangeInclusive<usize>)) -> Self {
+        Self::from_rust(pin_name, bits)
+    }
+}
+
 /// Utility functions for creating SubBus instances
 pub fn create_input_subbus(
     parent_bus: Rc<RefCell<dyn Pin>>,
@@ -547,4 +607,28 @@ mod tests {
         let result = InSubBus::new_single_bit(parent, 8);
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_out_subbus_defers_borrowed_connection() {
+        let parent = Rc::new(RefCell::new(Bus::new("parent".to_string(), 16)));
+        let target: Rc<RefCell<dyn Pin>> = Rc::new(RefCell::new(Bus::new("target".to_string(), 4)));
+        let mut subbus = OutSubBus::new(parent.clone(), 4, 4).unwrap();
+        subbus.connect(Rc::downgrade(&target));
+        
+        parent.borrow_mut().set_bus_voltage(0x00A0);
+        {
+            // Hold a borrow on the target across propagation
+            let _reader = target.borrow();
+            subbus.set_bus_voltage(0xA);
+            assert!(subbus.has_deferred(), "Borrowed pin should be deferred, not dropped");
+            
+            let err = subbus.flush_deferred().unwrap_err();
+            assert!(matches!(err, SimulatorError::BorrowConflict { ref pin } if pin == "target"));
+        }
+        
+        // Once the borrow is released the deferred value is delivered
+        subbus.flush_deferred().unwrap();
+        assert!(!subbus.has_deferred());
+        assert_eq!(target.borrow().bus_voltage(), 0xA);
+    }
+}
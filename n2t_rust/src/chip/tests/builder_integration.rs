@@ -3,6 +3,7 @@
 
 use crate::chip::builder::ChipBuilder;
 use crate::chip::pin::{HIGH, LOW};
+use crate::languages::hdl::HdlParser;
 // Note: Removed unused imports for cleaner compilation
 
 #[test]
@@ -239,8 +240,10 @@ fn test_builder_memory_functionality_spot_check() {
     ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(3);
     ram8.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
     
-    // Evaluate the RAM
-    ram8.eval().unwrap();
+    // Clock the write in
+    let clocked = ram8.as_clocked_mut().unwrap();
+    clocked.tick(HIGH).unwrap();
+    clocked.tock(LOW).unwrap();
     
     // Read from address 3
     ram8.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
@@ -325,4 +328,56 @@ fn test_builder_pin_width_consistency() {
     
     let output = and_chip.get_pin("out").unwrap().borrow().voltage(None).unwrap();
     assert_eq!(output, LOW); // AND(1, 0) = 0
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_pin_enumeration_is_deterministic() {
+    let mut parser = HdlParser::new().unwrap();
+    let hdl = r#"
+        CHIP Mixer {
+            IN sel, b, a, c;
+            OUT zed, out, mid;
+            
+            PARTS:
+            And(a=a, b=b, out=ab);
+            Or(a=ab, b=c, out=mid);
+            Mux(a=ab, b=c, sel=sel, out=out);
+            Not(in=ab, out=zed);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    
+    let builder = ChipBuilder::new();
+    let first = builder.build_chip(&hdl_chip).unwrap();
+    let second = builder.build_chip(&hdl_chip).unwrap();
+    
+    assert_eq!(first.input_pin_names_sorted(), vec!["a", "b", "c", "sel"]);
+    assert_eq!(first.output_pin_names_sorted(), vec!["mid", "out", "zed"]);
+    assert_eq!(first.input_pin_names_sorted(), second.input_pin_names_sorted());
+    assert_eq!(first.output_pin_names_sorted(), second.output_pin_names_sorted());
+    assert_eq!(first.internal_pin_names_sorted(), second.internal_pin_names_sorted());
+    
+    // Builtins enumerate the same way
+    let alu = builder.build_builtin_chip("ALU").unwrap();
+    assert_eq!(alu.input_pin_names_sorted(), vec!["f", "no", "nx", "ny", "x", "y", "zx", "zy"]);
+}
+
+#[test]
+fn test_builtin_fresh_preserves_configuration() {
+    let builder = ChipBuilder::new();
+    
+    for name in ["Nand", "Register8", "SGreater16", "ShiftRightArith16", "RAM8", "PC"] {
+        let chip = builder.build_builtin_chip(name).unwrap();
+        let copy = chip.fresh().unwrap();
+        assert_eq!(copy.name(), chip.name());
+        assert_eq!(copy.input_pin_names_sorted(), chip.input_pin_names_sorted());
+        assert_eq!(copy.output_pin_names_sorted(), chip.output_pin_names_sorted());
+    }
+    
+    // ROM contents are structure, so a fresh ROM keeps its program
+    let rom = ChipBuilder::new().with_rom_program(vec![0x1234]).build_builtin_chip("ROM32K").unwrap();
+    let mut copy = rom.fresh().unwrap();
+    copy.get_pin("address").unwrap().borrow_mut().set_bus_voltage(0);
+    copy.eval().unwrap();
+    assert_eq!(copy.get_pin("out").unwrap().borrow().bus_voltage(), 0x1234);
+}
@@ -82,6 +82,34 @@ fn test_and_chip() {
     assert_eq!(output, LOW);
 }
 
+#[test]
+fn test_and_chip_pin_helpers() {
+    let builder = ChipBuilder::new();
+    let mut and = builder.build_builtin_chip("And").unwrap();
+    
+    for (a, b, expected) in [(0, 0, 0), (0, 1, 0), (1, 0, 0), (1, 1, 1)] {
+        and.set_pin("a", a).unwrap();
+        and.set_pin("b", b).unwrap();
+        and.eval().unwrap();
+        assert_eq!(and.read_pin("out").unwrap(), expected, "And({}, {})", a, b);
+    }
+    
+    assert!(and.set_pin("missing", 1).is_err());
+    assert!(and.read_pin("missing").is_err());
+}
+
+#[test]
+fn test_pin_width() {
+    let builder = ChipBuilder::new();
+    let add16 = builder.build_builtin_chip("Add16").unwrap();
+    let and = builder.build_builtin_chip("And").unwrap();
+    
+    assert_eq!(add16.pin_width("a").unwrap(), 16);
+    assert_eq!(add16.pin_width("out").unwrap(), 16);
+    assert_eq!(and.pin_width("a").unwrap(), 1);
+    assert!(and.pin_width("missing").is_err());
+}
+
 #[test]
 fn test_or_chip() {
     let builder = ChipBuilder::new();
@@ -196,4 +224,26 @@ fn test_dmux_chip() {
     let b_output = dmux_chip.get_pin("b").unwrap().borrow().voltage(None).unwrap();
     assert_eq!(a_output, LOW);
     assert_eq!(b_output, HIGH);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_dmux_multi_way_clears_unselected_outputs() {
+    let builder = ChipBuilder::new();
+    
+    for (name, outputs) in [("DMux4Way", &["a", "b", "c", "d"][..]), ("DMux8Way", &["a", "b", "c", "d", "e", "f", "g", "h"][..])] {
+        let mut dmux = builder.build_builtin_chip(name).unwrap();
+        dmux.get_pin("in").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+        
+        // Walk the selector across every output; only the current one may stay HIGH
+        for (selected, _) in outputs.iter().enumerate() {
+            dmux.get_pin("sel").unwrap().borrow_mut().set_bus_voltage(selected as u16);
+            dmux.eval().unwrap();
+            
+            for (i, output) in outputs.iter().enumerate() {
+                let voltage = dmux.get_pin(output).unwrap().borrow().voltage(None).unwrap();
+                let expected = if i == selected { HIGH } else { LOW };
+                assert_eq!(voltage, expected, "{} sel={} output {} should be {}", name, selected, output, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,642 @@
+// Tests for composite Chip state handling outside of wiring
+// Covers reset behaviour, persistent input stimulus and broadcast clocking
+
+use crate::chip::*;
+use crate::chip::builder::ChipBuilder;
+use crate::chip::clock::Clock;
+use crate::languages::hdl::HdlParser;
+use crate::chip::pin::{HIGH, LOW};
+use crate::error::SimulatorError;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+
+fn chip_with_inputs() -> Chip {
+    let mut chip = Chip::new("TestChip".to_string());
+    chip.add_input_pin("reset".to_string(), Rc::new(RefCell::new(Bus::new("reset".to_string(), 1))));
+    chip.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))));
+    chip
+}
+
+#[test]
+fn test_latched_input_survives_reset() {
+    let mut chip = chip_with_inputs();
+    
+    chip.latch_input("reset", 1).unwrap();
+    chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x1234);
+    assert_eq!(chip.get_pin("reset").unwrap().borrow().voltage(None).unwrap(), HIGH);
+    
+    chip.reset().unwrap();
+    
+    // Latched pin is re-applied, ordinary input is zeroed
+    assert_eq!(chip.get_pin("reset").unwrap().borrow().voltage(None).unwrap(), HIGH);
+    assert_eq!(chip.get_pin("in").unwrap().borrow().bus_voltage(), 0);
+}
+
+#[test]
+fn test_unlatch_input() {
+    let mut chip = chip_with_inputs();
+    
+    chip.latch_input("in", 0xBEEF).unwrap();
+    chip.unlatch_input("in");
+    chip.reset().unwrap();
+    
+    assert_eq!(chip.get_pin("in").unwrap().borrow().bus_voltage(), 0);
+    assert_eq!(chip.get_pin("reset").unwrap().borrow().voltage(None).unwrap(), LOW);
+}
+
+#[test]
+fn test_latch_unknown_input_fails() {
+    let mut chip = chip_with_inputs();
+    assert!(chip.latch_input("missing", 1).is_err());
+}
+
+#[test]
+fn test_get_pin_ci() {
+    let mut chip = chip_with_inputs();
+    chip.add_output_pin("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 1))));
+    
+    assert!(chip.get_pin("OUT").is_err(), "Exact lookup stays case-sensitive");
+    let pin = chip.get_pin_ci("OUT").unwrap();
+    assert_eq!(pin.borrow().name(), "out");
+    assert_eq!(chip.get_pin_ci("Reset").unwrap().borrow().name(), "reset");
+    assert!(chip.get_pin_ci("missing").is_err());
+    
+    // Two pins differing only by case cannot be resolved
+    chip.add_output_pin("Out".to_string(), Rc::new(RefCell::new(Bus::new("Out".to_string(), 1))));
+    assert_eq!(chip.get_pin_ci("out").unwrap().borrow().name(), "out", "Exact match still wins");
+    let err = chip.get_pin_ci("OUT").unwrap_err();
+    assert!(err.to_string().contains("ambiguous"), "unexpected error: {}", err);
+}
+
+fn composite_register() -> Chip {
+    let mut chip = Chip::new("Reg".to_string());
+    chip.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))));
+    chip.add_input_pin("load".to_string(), Rc::new(RefCell::new(Bus::new("load".to_string(), 1))));
+    chip.add_output_pin("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))));
+    
+    let register = ChipBuilder::new().build_builtin_chip("Register").unwrap();
+    let connections = ["in", "load", "out"]
+        .iter()
+        .map(|name| Connection::new(PinSide::new(name.to_string()), PinSide::new(name.to_string())))
+        .collect();
+    chip.wire(register, connections).unwrap();
+    chip
+}
+
+#[test]
+fn test_stopped_clock_closes_receivers() {
+    let mut clock = Clock::new();
+    let mut receiver = clock.subscribe();
+    clock.tick().unwrap();
+    clock.stop();
+    
+    // Edges published before the stop are still delivered
+    assert!(!receiver.is_stopped());
+    assert!(receiver.try_recv().is_some());
+    assert!(receiver.is_stopped());
+    assert!(clock.tick().is_err(), "A stopped clock cannot tick");
+    assert!(clock.subscribe().is_stopped());
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_run_for_then_stop_ends_receive_loops() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    
+    // Receivers are not Send (a synchronous clock's queues are Rc), so the counter is a local task
+    let local = tokio::task::LocalSet::new();
+    let (cycles, rising_edges) = local.block_on(&rt, async {
+        let mut clock = TokioClock::new();
+        let mut receiver = clock.subscribe();
+        
+        // Count rising edges until the clock stops
+        let counter = tokio::task::spawn_local(async move {
+            let mut rising_edges = 0;
+            while let Some(tick) = receiver.recv().await {
+                if tick.level == HIGH {
+                    rising_edges += 1;
+                }
+            }
+            rising_edges
+        });
+        
+        let cycles = clock.run_for(std::time::Duration::from_millis(20)).await.unwrap();
+        clock.stop();
+        (cycles, counter.await.unwrap())
+    });
+    
+    assert!(cycles > 0, "run_for should clock at least once in 20ms");
+    // The counter yields after every edge, so it never falls behind the channel
+    assert_eq!(rising_edges, cycles);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_subscribed_chip_follows_broadcast_clock() {
+    let clock = TokioClock::new();
+    let mut chip = composite_register();
+    chip.subscribe_to_clock(clock.subscribe());
+    
+    chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x1234);
+    chip.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    chip.eval().unwrap();
+    assert_eq!(chip.get_pin("out").unwrap().borrow().bus_voltage(), 0, "No edge published yet");
+    
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let mut clock = rt.block_on(async move {
+        // Publish a full cycle from another task; the chip picks it up on its next eval
+        let publisher = tokio::spawn(async move {
+            let mut clock = clock;
+            clock.tick().unwrap();
+            clock.tick().unwrap();
+            clock
+        });
+        publisher.await.unwrap()
+    });
+    
+    chip.eval().unwrap();
+    assert_eq!(chip.get_pin("out").unwrap().borrow().bus_voltage(), 0x1234);
+    
+    // Without load the stored value holds across further cycles
+    chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x5678);
+    chip.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
+    clock.tick().unwrap();
+    clock.tick().unwrap();
+    chip.eval().unwrap();
+    assert_eq!(chip.get_pin("out").unwrap().borrow().bus_voltage(), 0x1234);
+}
+
+#[test]
+fn test_synchronous_clock_source_drives_chip() {
+    // No async runtime anywhere: edges queue per subscriber until the chip's next eval
+    let mut clock = Clock::new();
+    let mut chip = composite_register();
+    chip.subscribe_to_clock(clock.subscribe());
+    
+    let source: &mut dyn ClockSource = &mut clock;
+    chip.set_pin("in", 0x0042).unwrap();
+    chip.set_pin("load", 1).unwrap();
+    source.tick().unwrap();
+    assert_eq!(source.level(), HIGH);
+    source.tick().unwrap();
+    assert_eq!(source.ticks(), 2);
+    
+    chip.eval().unwrap();
+    assert_eq!(chip.read_pin("out").unwrap(), 0x0042);
+    
+    // A resubscribed receiver only sees later edges
+    let mut late = chip.fresh().unwrap();
+    late.set_pin("in", 0x0099).unwrap();
+    late.set_pin("load", 1).unwrap();
+    late.eval().unwrap();
+    assert_eq!(late.read_pin("out").unwrap(), 0, "edges from before the copy are not replayed");
+    source.tick().unwrap();
+    source.tick().unwrap();
+    late.eval().unwrap();
+    assert_eq!(late.read_pin("out").unwrap(), 0x0099);
+}
+
+#[test]
+fn test_reset_discards_pending_clock_edges() {
+    let mut clock = Clock::new();
+    let mut chip = composite_register();
+    chip.subscribe_to_clock(clock.subscribe());
+    
+    chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(7);
+    chip.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    clock.tick().unwrap();
+    clock.tick().unwrap();
+    chip.reset().unwrap();
+    chip.eval().unwrap();
+    
+    assert_eq!(chip.get_pin("out").unwrap().borrow().bus_voltage(), 0);
+}
+
+#[test]
+fn test_fresh_composite_is_independent() {
+    let mut parser = HdlParser::new().unwrap();
+    let hdl = r#"
+        CHIP AndOr {
+            IN a, b, c;
+            OUT out;
+            
+            PARTS:
+            And(a=a, b=b, out=ab);
+            Or(a=ab, b=c, out=out);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut original = ChipBuilder::new().build_chip(&hdl_chip).unwrap();
+    
+    original.get_pin("a").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    original.get_pin("b").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    original.eval().unwrap();
+    assert_eq!(original.get_pin("out").unwrap().borrow().voltage(None).unwrap(), HIGH);
+    
+    // The copy starts from zero rather than the original's current state
+    let mut copy = original.fresh().unwrap();
+    assert_eq!(copy.name(), "AndOr");
+    assert_eq!(copy.input_pin_names_sorted(), original.input_pin_names_sorted());
+    assert_eq!(copy.internal_pin_names_sorted(), original.internal_pin_names_sorted());
+    assert_eq!(copy.get_pin("a").unwrap().borrow().voltage(None).unwrap(), LOW);
+    assert_eq!(copy.get_pin("out").unwrap().borrow().voltage(None).unwrap(), LOW);
+    
+    // Driving the copy leaves the original untouched and vice versa
+    copy.get_pin("c").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    copy.eval().unwrap();
+    assert_eq!(copy.get_pin("out").unwrap().borrow().voltage(None).unwrap(), HIGH);
+    assert_eq!(original.get_pin("c").unwrap().borrow().voltage(None).unwrap(), LOW);
+    
+    original.get_pin("a").unwrap().borrow_mut().pull(LOW, None).unwrap();
+    original.eval().unwrap();
+    assert_eq!(original.get_pin("out").unwrap().borrow().voltage(None).unwrap(), LOW);
+    assert_eq!(copy.get_pin("out").unwrap().borrow().voltage(None).unwrap(), HIGH);
+}
+
+#[test]
+fn test_bus_on_change_fires_after_eval() {
+    let mut chip = Chip::new("Inverter".to_string());
+    chip.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 1))));
+    let out = Rc::new(RefCell::new(Bus::new("out".to_string(), 1)));
+    chip.add_output_pin("out".to_string(), out.clone());
+    
+    let not = ChipBuilder::new().build_builtin_chip("Not").unwrap();
+    let connections = ["in", "out"]
+        .iter()
+        .map(|name| Connection::new(PinSide::new(name.to_string()), PinSide::new(name.to_string())))
+        .collect();
+    chip.wire(not, connections).unwrap();
+    
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let log = seen.clone();
+    out.borrow_mut().on_change(Box::new(move |value| log.borrow_mut().push(value)));
+    
+    chip.eval().unwrap();
+    assert_eq!(*seen.borrow(), vec![1], "Not(0) drives out HIGH");
+    
+    // Re-evaluating with the same input changes nothing, so the watcher stays quiet
+    chip.eval().unwrap();
+    assert_eq!(*seen.borrow(), vec![1]);
+    
+    chip.set_pin("in", 1).unwrap();
+    chip.eval().unwrap();
+    assert_eq!(*seen.borrow(), vec![1, 0]);
+}
+
+#[test]
+fn test_bus_on_change_runs_while_bus_is_borrowed() {
+    let bus = Rc::new(RefCell::new(Bus::new("out".to_string(), 4)));
+    let watched = Rc::downgrade(&bus);
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let log = seen.clone();
+    bus.borrow_mut().on_change(Box::new(move |value| {
+        // The bus is still held by the write that changed it, so only the passed value is readable
+        let bus = watched.upgrade().unwrap();
+        log.borrow_mut().push((value, bus.try_borrow().is_err()));
+    }));
+    
+    bus.borrow_mut().set_bus_voltage(0b1010);
+    assert_eq!(*seen.borrow(), vec![(0b1010, true)]);
+    
+    // Once the write returns the bus is free again
+    assert_eq!(bus.borrow().bus_voltage(), 0b1010);
+}
+
+// a,b,c -> And -> ab -> Or(ab, c) -> o -> Not -> out, plus Xor(a, c) -> x
+fn gate_network() -> Chip {
+    let mut chip = Chip::new("GateNetwork".to_string());
+    for name in ["a", "b", "c"] {
+        chip.add_input_pin(name.to_string(), Rc::new(RefCell::new(Bus::new(name.to_string(), 1))));
+    }
+    for name in ["ab", "o"] {
+        chip.add_internal_pin(name.to_string(), Rc::new(RefCell::new(Bus::new(name.to_string(), 1))));
+    }
+    for name in ["out", "x"] {
+        chip.add_output_pin(name.to_string(), Rc::new(RefCell::new(Bus::new(name.to_string(), 1))));
+    }
+    
+    let builder = ChipBuilder::new();
+    let parts: [(&str, &[(&str, &str)]); 4] = [
+        ("And", &[("a", "a"), ("b", "b"), ("ab", "out")]),
+        ("Or", &[("ab", "a"), ("c", "b"), ("o", "out")]),
+        ("Not", &[("o", "in"), ("out", "out")]),
+        ("Xor", &[("a", "a"), ("c", "b"), ("x", "out")]),
+    ];
+    for (part_name, pins) in parts {
+        let connections = pins
+            .iter()
+            .map(|(host, part)| Connection::new(PinSide::new(host.to_string()), PinSide::new(part.to_string())))
+            .collect();
+        chip.wire(builder.build_builtin_chip(part_name).unwrap(), connections).unwrap();
+    }
+    chip.set_profiling(true);
+    chip
+}
+
+#[test]
+fn test_incremental_eval_matches_full_eval() {
+    let mut incremental = gate_network();
+    let mut full = gate_network();
+    full.set_incremental(false);
+    
+    // Small LCG so the input sequence is random-looking but reproducible
+    let mut seed: u32 = 0x2545_f491;
+    for _ in 0..200 {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        for (bit, name) in ["a", "b", "c"].iter().enumerate() {
+            let value = ((seed >> (16 + bit)) & 1) as u16;
+            incremental.set_pin(name, value).unwrap();
+            full.set_pin(name, value).unwrap();
+        }
+        incremental.eval().unwrap();
+        full.eval().unwrap();
+        
+        for name in ["ab", "o", "out", "x"] {
+            assert_eq!(incremental.read_pin(name).unwrap(), full.read_pin(name).unwrap(), "pin {}", name);
+        }
+    }
+    
+    assert_eq!(full.sub_chip_evals(), 200 * 4);
+    assert!(incremental.sub_chip_evals() < full.sub_chip_evals());
+}
+
+#[test]
+fn test_incremental_eval_skips_stable_parts() {
+    let mut chip = gate_network();
+    
+    // Every part runs on the first eval
+    chip.eval().unwrap();
+    assert_eq!(chip.sub_chip_evals(), 4);
+    assert_eq!(chip.read_pin("out").unwrap(), 1);
+    
+    // Nothing changed, nothing re-evaluated
+    chip.eval().unwrap();
+    assert_eq!(chip.sub_chip_evals(), 4);
+    
+    // c feeds Or and Xor; Or's new output makes Not dirty, And stays untouched
+    chip.set_pin("c", 1).unwrap();
+    chip.eval().unwrap();
+    assert_eq!(chip.sub_chip_evals(), 7);
+    assert_eq!(chip.read_pin("out").unwrap(), 0);
+    assert_eq!(chip.read_pin("x").unwrap(), 1);
+    
+    // A reset clears part outputs, so everything runs again
+    chip.reset().unwrap();
+    chip.eval().unwrap();
+    assert_eq!(chip.sub_chip_evals(), 11);
+    assert_eq!(chip.read_pin("out").unwrap(), 1);
+}
+
+#[test]
+fn test_stats_count_sub_chip_calls() {
+    // in -> Not -> DFF -> Not -> out
+    let mut chip = Chip::new("Pipeline".to_string());
+    chip.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 1))));
+    chip.add_output_pin("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 1))));
+    for name in ["n", "q"] {
+        chip.add_internal_pin(name.to_string(), Rc::new(RefCell::new(Bus::new(name.to_string(), 1))));
+    }
+    
+    let builder = ChipBuilder::new();
+    let parts: [(&str, &str, &str); 3] = [("Not", "in", "n"), ("DFF", "n", "q"), ("Not", "q", "out")];
+    for (part_name, input, output) in parts {
+        let connections = vec![
+            Connection::new(PinSide::new(input.to_string()), PinSide::new("in".to_string())),
+            Connection::new(PinSide::new(output.to_string()), PinSide::new("out".to_string())),
+        ];
+        chip.wire(builder.build_builtin_chip(part_name).unwrap(), connections).unwrap();
+    }
+    chip.set_profiling(true);
+    chip.set_incremental(false);
+    
+    // A full eval touches each of the three parts exactly once
+    chip.eval().unwrap();
+    assert_eq!(chip.stats(), ChipStats { evals: 3, ticks: 0, tocks: 0 });
+    
+    // Only the DFF sees the clock edges; tick settles the network first
+    chip.tick(HIGH).unwrap();
+    chip.tock(LOW).unwrap();
+    assert_eq!(chip.stats(), ChipStats { evals: 6, ticks: 1, tocks: 1 });
+    
+    chip.reset_stats();
+    chip.set_profiling(false);
+    chip.eval().unwrap();
+    assert_eq!(chip.stats(), ChipStats::default());
+}
+
+#[test]
+fn test_clear_memory_keeps_inputs() {
+    let mut chip = Chip::new("Store".to_string());
+    for (name, width) in [("in", 16), ("load", 1), ("address", 3)] {
+        chip.add_input_pin(name.to_string(), Rc::new(RefCell::new(Bus::new(name.to_string(), width))));
+    }
+    chip.add_output_pin("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))));
+    let connections = ["in", "load", "address", "out"]
+        .iter()
+        .map(|name| Connection::new(PinSide::new(name.to_string()), PinSide::new(name.to_string())))
+        .collect();
+    chip.wire(ChipBuilder::new().build_builtin_chip("RAM8").unwrap(), connections).unwrap();
+    
+    chip.set_pin("in", 1234).unwrap();
+    chip.set_pin("address", 5).unwrap();
+    chip.set_pin("load", 1).unwrap();
+    chip.tick(HIGH).unwrap();
+    chip.tock(LOW).unwrap();
+    chip.set_pin("load", 0).unwrap();
+    chip.eval().unwrap();
+    assert_eq!(chip.read_pin("out").unwrap(), 1234);
+    
+    chip.clear_memory();
+    chip.eval().unwrap();
+    assert_eq!(chip.read_pin("out").unwrap(), 0, "RAM contents are cleared");
+    assert_eq!(chip.read_pin("in").unwrap(), 1234, "Inputs are left as they were");
+    assert_eq!(chip.read_pin("address").unwrap(), 5);
+}
+
+// Part whose eval always fails, standing in for a broken builtin
+#[derive(Debug)]
+struct BrokenPart {
+    pins: HashMap<String, Rc<RefCell<dyn Pin>>>,
+}
+
+impl ChipInterface for BrokenPart {
+    fn name(&self) -> &str { "Broken" }
+    fn input_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> { &self.pins }
+    fn output_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> { &self.pins }
+    fn internal_pins(&self) -> &HashMap<String, Rc<RefCell<dyn Pin>>> { &self.pins }
+    
+    fn get_pin(&self, name: &str) -> crate::error::Result<Rc<RefCell<dyn Pin>>> {
+        Err(SimulatorError::PinNotFound { pin: name.to_string(), chip: "Broken".to_string() })
+    }
+    
+    fn is_input_pin(&self, _name: &str) -> bool { false }
+    fn is_output_pin(&self, _name: &str) -> bool { false }
+    
+    fn eval(&mut self) -> crate::error::Result<()> {
+        Err(SimulatorError::PinNotFound { pin: "x".to_string(), chip: "Broken".to_string() })
+    }
+    
+    fn reset(&mut self) -> crate::error::Result<()> { Ok(()) }
+    
+    fn fresh(&self) -> crate::error::Result<Box<dyn ChipInterface>> {
+        Ok(Box::new(BrokenPart { pins: HashMap::new() }))
+    }
+}
+
+#[test]
+fn test_eval_error_carries_chip_path() {
+    let mut alu = Chip::new("ALU".to_string());
+    alu.add_sub_chip(Box::new(BrokenPart { pins: HashMap::new() }));
+    let mut cpu = Chip::new("CPU".to_string());
+    cpu.add_sub_chip(Box::new(alu));
+    let mut computer = Chip::new("Computer".to_string());
+    computer.add_sub_chip(Box::new(cpu));
+    
+    let err = computer.eval().unwrap_err();
+    assert_eq!(err.to_string(), "in Computer > CPU > ALU: Pin 'x' not found in chip 'Broken'");
+    assert!(matches!(err.root_cause(), SimulatorError::PinNotFound { pin, .. } if pin == "x"));
+}
+
+#[test]
+fn test_try_debug_state_tolerates_borrowed_pins() {
+    let chip = composite_register();
+    chip.set_pin("in", 0x0042).unwrap();
+    
+    let state = chip.try_debug_state().unwrap();
+    assert!(state.is_complete());
+    assert_eq!(state.inputs, vec![("in".to_string(), Some(0x0042)), ("load".to_string(), Some(0))]);
+    
+    // A pin held mutably (as during propagation) reads as None instead of panicking
+    let in_pin = chip.get_pin("in").unwrap();
+    let held = in_pin.borrow_mut();
+    let state = chip.try_debug_state().unwrap();
+    assert!(!state.is_complete());
+    assert_eq!(state.inputs, vec![("in".to_string(), None), ("load".to_string(), Some(0))]);
+    assert_eq!(state.outputs, vec![("out".to_string(), Some(0))]);
+    drop(held);
+    
+    // Nothing readable at all gives no state
+    let mut lone = Chip::new("Lone".to_string());
+    lone.add_input_pin("a".to_string(), Rc::new(RefCell::new(Bus::new("a".to_string(), 1))));
+    let a_pin = lone.get_pin("a").unwrap();
+    let _held = a_pin.borrow_mut();
+    assert!(lone.try_debug_state().is_none());
+}
+
+#[test]
+fn test_eval_report_lists_changed_outputs() {
+    let mut parser = HdlParser::new().unwrap();
+    let hdl_chip = parser.parse(r#"
+        CHIP Split {
+            IN a, b;
+            OUT notA, notB, both;
+            
+            PARTS:
+            Not(in=a, out=notA);
+            Not(in=b, out=notB);
+            And(a=a, b=b, out=both);
+        }
+    "#).unwrap();
+    let mut chip = ChipBuilder::new().build_chip(&hdl_chip).unwrap();
+    
+    assert_eq!(chip.eval_report().unwrap(), vec!["notA", "notB"]);
+    assert!(chip.eval_report().unwrap().is_empty(), "nothing changed since the last eval");
+    
+    chip.set_pin("a", 1).unwrap();
+    assert_eq!(chip.eval_report().unwrap(), vec!["notA"]);
+    
+    chip.set_pin("b", 1).unwrap();
+    assert_eq!(chip.eval_report().unwrap(), vec!["both", "notB"]);
+}
+
+#[test]
+fn test_alias_pin_shares_the_bus() {
+    let mut chip = Chip::new("Aliased".to_string());
+    chip.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))));
+    chip.add_output_pin("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))));
+    chip.wire(Box::new(Not16Chip::new()), vec![
+        Connection::new(PinSide::from("in"), PinSide::from("in")),
+        Connection::new(PinSide::from("out"), PinSide::from("out")),
+    ]).unwrap();
+    chip.alias_pin("out", "result").unwrap();
+    
+    assert!(Rc::ptr_eq(&chip.get_pin("out").unwrap(), &chip.get_pin("result").unwrap()));
+    assert!(chip.is_output_pin("result"));
+    assert_eq!(chip.output_pin_names_sorted(), vec!["out"]);
+    
+    // The part drives both names
+    chip.set_pin("in", 0x00ff).unwrap();
+    chip.eval().unwrap();
+    assert_eq!(chip.read_pin("result").unwrap(), 0xff00);
+    
+    // A write through the alias is visible under the original name
+    chip.set_pin("result", 0x1234).unwrap();
+    assert_eq!(chip.read_pin("out").unwrap(), 0x1234);
+    
+    // Aliases of aliases reach the same pin; existing names and missing targets are refused
+    chip.alias_pin("result", "q").unwrap();
+    assert_eq!(chip.read_pin("q").unwrap(), 0x1234);
+    assert!(chip.alias_pin("out", "in").is_err());
+    assert!(chip.alias_pin("in", "result").is_err());
+    assert!(chip.alias_pin("missing", "other").is_err());
+    
+    // Copies keep the alias
+    let copy = chip.fresh().unwrap();
+    copy.set_pin("result", 7).unwrap();
+    assert_eq!(copy.read_pin("out").unwrap(), 7);
+}
+
+#[test]
+fn test_output_slices_reach_later_parts_in_one_eval() {
+    // Not16's low byte reaches And16 through output slices, which must be current before And16
+    // evaluates in the same pass rather than one eval behind
+    let mut parser = HdlParser::new().unwrap();
+    let hdl_chip = parser.parse(r#"
+        CHIP SliceChain {
+            IN in[16];
+            OUT out[16];
+            PARTS:
+            Not16(in=in, out[0..7]=low, out[8..15]=high);
+            And16(a[0..7]=low, a[8..15]=high, b=true, out=out);
+        }
+    "#).unwrap();
+    let mut chip = ChipBuilder::new().build_chip(&hdl_chip).unwrap();
+    
+    for value in [0x00ffu16, 0x1234, 0xf0f0] {
+        chip.set_pin("in", value).unwrap();
+        chip.eval().unwrap();
+        assert_eq!(chip.read_pin("out").unwrap(), !value, "a single eval settles in={:#06x}", value);
+    }
+}
+
+#[test]
+fn test_constants_survive_reset() {
+    let mut parser = HdlParser::new().unwrap();
+    let hdl_chip = parser.parse(r#"
+        CHIP Constants {
+            IN a[16];
+            OUT x[16], y[16];
+            PARTS:
+            And16(a=a, b=true, out=x);
+            Or16(a=a, b[0..3]=%B0101, out=y);
+        }
+    "#).unwrap();
+    let mut chip = ChipBuilder::new().build_chip(&hdl_chip).unwrap();
+    
+    for _ in 0..2 {
+        chip.set_pin("a", 0x00f0).unwrap();
+        chip.eval().unwrap();
+        assert_eq!(chip.read_pin("x").unwrap(), 0x00f0);
+        assert_eq!(chip.read_pin("y").unwrap(), 0x00f5);
+        
+        // Part resets clear their inputs, the constant ones included
+        chip.reset().unwrap();
+    }
+}
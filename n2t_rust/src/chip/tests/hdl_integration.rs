@@ -1,8 +1,11 @@
 // Tests for HDL parsing and chip creation integration
 // Covers HDL language parsing, chip construction, and error handling
 
-use crate::chip::builder::ChipBuilder;
-use crate::languages::hdl::HdlParser;
+use crate::chip::builder::{ChipBuilder, Diagnostic};
+use crate::chip::pin::{HIGH, LOW};
+use crate::chip::WireError;
+use crate::error::SimulatorError;
+use crate::languages::hdl::{HdlParser, LintWarning, WireSide};
 
 #[test]
 fn test_hdl_chip_creation_with_wide_buses() {
@@ -30,6 +33,57 @@ fn test_hdl_chip_creation_with_wide_buses() {
     assert_eq!(hdl_chip.outputs[0].width, Some(16));
 }
 
+#[test]
+fn test_hdl_builtin_alias() {
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP MyNand {
+            IN a, b;
+            OUT out;
+            BUILTIN Nand;
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    assert_eq!(hdl_chip.name, "MyNand");
+    assert!(hdl_chip.is_builtin);
+    assert_eq!(hdl_chip.builtin_name.as_deref(), Some("Nand"));
+    assert!(hdl_chip.parts.is_empty());
+    
+    // Dispatch goes through the named builtin, not the chip's own name
+    let mut chip = ChipBuilder::new().build_chip(&hdl_chip).unwrap();
+    chip.set_pin("a", 1).unwrap();
+    chip.set_pin("b", 1).unwrap();
+    chip.eval().unwrap();
+    assert_eq!(chip.read_pin("out").unwrap(), 0);
+    
+    // The bare form leaves the name unset
+    let bare = parser.parse("CHIP Nand {\n IN a, b;\n OUT out;\n BUILTIN;\n}").unwrap();
+    assert!(bare.is_builtin);
+    assert_eq!(bare.builtin_name, None);
+}
+
+#[test]
+fn test_hdl_builtin_header_must_match_builtin() {
+    let mut parser = HdlParser::new().unwrap();
+    let builder = ChipBuilder::new();
+    
+    let matching = parser.parse("CHIP Not16 {\n IN in[16];\n OUT out[16];\n BUILTIN;\n}").unwrap();
+    assert!(builder.build_chip(&matching).is_ok());
+    
+    let narrow = parser.parse("CHIP Not16 {\n IN in[8];\n OUT out[16];\n BUILTIN;\n}").unwrap();
+    let err = match builder.build_chip(&narrow) {
+        Ok(_) => panic!("in[8] must not match Not16"),
+        Err(e) => e.to_string(),
+    };
+    assert!(err.contains("Width mismatch"), "{}", err);
+    assert!(err.contains("'in'") && err.contains("8") && err.contains("16"), "{}", err);
+    
+    let renamed = parser.parse("CHIP Not16 {\n IN a[16];\n OUT out[16];\n BUILTIN;\n}").unwrap();
+    assert!(builder.build_chip(&renamed).is_err());
+}
+
 #[test]
 fn test_hdl_single_bit_pins() {
     let mut parser = HdlParser::new().unwrap();
@@ -209,4 +263,619 @@ fn test_hdl_constants_and_pin_ranges() {
     assert_eq!(hdl_chip.inputs[0].width, Some(16));
     assert_eq!(hdl_chip.outputs[0].width, Some(8));
     assert_eq!(hdl_chip.outputs[1].width, Some(8));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_built_composite_ranges_on_both_wire_sides() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    // Bits 4..7 of x go through the low nibble of a 16-bit part and land in bits 8..11 of y,
+    // while a 4-bit part copies bits 0..3 of x into bits 0..3 of y
+    let hdl = r#"
+        CHIP Route {
+            IN x[16];
+            OUT y[16];
+            
+            PARTS:
+            And16(a[0..3]=x[4..7], b[0..3]=true, out[0..3]=y[8..11]);
+            And4(a=x[0..3], b=true, out=y[0..3]);
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    
+    for x in [0x0000, 0x0050, 0x00f0, 0x1234, 0xffff] {
+        chip.set_pin("x", x).unwrap();
+        chip.eval().unwrap();
+        let expected = ((x >> 4) & 0xf) << 8 | (x & 0xf);
+        assert_eq!(chip.read_pin("y").unwrap(), expected, "x={:#06x}", x);
+    }
+    
+    // Both slices of a connection must be the same width
+    let mismatched = r#"
+        CHIP Route {
+            IN x[16];
+            OUT y[16];
+            
+            PARTS:
+            And16(a[0..2]=x[4..7], b=true, out[0..3]=y[8..11]);
+        }
+    "#;
+    let hdl_chip = parser.parse(mismatched).unwrap();
+    let err = match builder.build_chip(&hdl_chip) {
+        Ok(_) => panic!("a 3-bit part slice must not accept a 4-bit host slice"),
+        Err(e) => e.to_string(),
+    };
+    assert!(err.contains("Width mismatch"), "{}", err);
+}
+
+#[test]
+fn test_narrow_output_takes_slice_of_wider_part() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    // Only the low nibble of the 16-bit sum reaches the 4-bit output
+    let hdl = r#"
+        CHIP LowSum {
+            IN a[16], b[16];
+            OUT out[4];
+            
+            PARTS:
+            Add16(a=a, b=b, out[0..3]=out);
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    assert_eq!(chip.pin_width("out").unwrap(), 4);
+    
+    for (a, b) in [(0x0001, 0x0002), (0x0009, 0x0008), (0x1234, 0x0ff0), (0xffff, 0x0001)] {
+        chip.set_pin("a", a).unwrap();
+        chip.set_pin("b", b).unwrap();
+        chip.eval().unwrap();
+        let sum: u16 = a.wrapping_add(b);
+        assert_eq!(chip.read_pin("out").unwrap(), sum & 0xf, "{:#06x} + {:#06x}", a, b);
+    }
+    
+    // Truncation has to be spelled out with a range
+    let implicit = r#"
+        CHIP LowSum {
+            IN a[16], b[16];
+            OUT out[4];
+            
+            PARTS:
+            Add16(a=a, b=b, out=out);
+        }
+    "#;
+    let hdl_chip = parser.parse(implicit).unwrap();
+    let err = match builder.build_chip(&hdl_chip) {
+        Ok(_) => panic!("a 16-bit part output must not drive a 4-bit host output unsliced"),
+        Err(e) => e.to_string(),
+    };
+    assert!(err.contains("Width mismatch"), "{}", err);
+}
+
+#[test]
+fn test_built_composite_routes_internal_pin() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP MyNand {
+            IN x, y;
+            OUT out;
+            
+            PARTS:
+            And(a=x, b=y, out=t);
+            Not(in=t, out=out);
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    
+    // Only `t` is internal; part pin names must not leak into the host
+    assert_eq!(chip.internal_pins().len(), 1);
+    assert!(chip.internal_pins().contains_key("t"));
+    
+    for (x, y, expected) in [(LOW, LOW, HIGH), (LOW, HIGH, HIGH), (HIGH, LOW, HIGH), (HIGH, HIGH, LOW)] {
+        chip.get_pin("x").unwrap().borrow_mut().pull(x, None).unwrap();
+        chip.get_pin("y").unwrap().borrow_mut().pull(y, None).unwrap();
+        chip.eval().unwrap();
+        let output = chip.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+        assert_eq!(output, expected, "MyNand({}, {}) should be {}", x, y, expected);
+    }
+}
+
+#[test]
+fn test_built_composite_reads_output_pin_downstream() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP AndBoth {
+            IN a, b;
+            OUT out, nout;
+            
+            PARTS:
+            And(a=a, b=b, out=out);
+            Not(in=out, out=nout);
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    
+    for (a, b) in [(LOW, LOW), (LOW, HIGH), (HIGH, LOW), (HIGH, HIGH)] {
+        chip.get_pin("a").unwrap().borrow_mut().pull(a, None).unwrap();
+        chip.get_pin("b").unwrap().borrow_mut().pull(b, None).unwrap();
+        chip.eval().unwrap();
+        
+        let and = if a == HIGH && b == HIGH { HIGH } else { LOW };
+        let out = chip.get_pin("out").unwrap().borrow().voltage(None).unwrap();
+        let nout = chip.get_pin("nout").unwrap().borrow().voltage(None).unwrap();
+        assert_eq!(out, and);
+        assert_eq!(nout, if and == HIGH { LOW } else { HIGH }, "nout should invert the host output");
+    }
+}
+
+#[test]
+fn test_built_composite_wide_internal_pin() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP AddOne {
+            IN a[16], b[16];
+            OUT out[16];
+            
+            PARTS:
+            Add16(a=a, b=b, out=sum);
+            Inc16(in=sum, out=out);
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    
+    // The internal pin takes the width of the part output driving it
+    assert_eq!(chip.get_pin("sum").unwrap().borrow().width(), 16);
+    
+    chip.get_pin("a").unwrap().borrow_mut().set_bus_voltage(1000);
+    chip.get_pin("b").unwrap().borrow_mut().set_bus_voltage(234);
+    chip.eval().unwrap();
+    assert_eq!(chip.get_pin("out").unwrap().borrow().bus_voltage(), 1235);
+}
+
+#[test]
+fn test_internal_pin_width_from_slices() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP SplitNot {
+            IN a[16];
+            OUT out[16];
+            
+            PARTS:
+            Not8(in=a[0..7], out=tmp[0..7]);
+            Not8(in=a[8..15], out=tmp[8..15]);
+            Not16(in=tmp[0..15], out=out);
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    
+    // Only ever sliced, so the highest slice sets the width
+    assert_eq!(chip.get_pin("tmp").unwrap().borrow().width(), 16);
+    
+    chip.get_pin("a").unwrap().borrow_mut().set_bus_voltage(0x12f0);
+    chip.eval().unwrap();
+    assert_eq!(chip.get_pin("out").unwrap().borrow().bus_voltage(), 0x12f0);
+    
+    // A pin also wired whole keeps its part-pin width, which must cover the slices
+    let hdl_chip = parser.parse(r#"
+        CHIP TooNarrow {
+            IN a[8];
+            OUT out;
+            
+            PARTS:
+            Not8(in=a, out=tmp);
+            Not(in=tmp[12], out=out);
+        }
+    "#).unwrap();
+    let error = builder.build_chip(&hdl_chip).expect_err("tmp is 8 bits but tmp[12] is read");
+    assert!(error.to_string().contains("Internal pin 'tmp' in chip TooNarrow is 8 bits wide"), "{}", error);
+}
+
+#[test]
+fn test_built_composite_wide_constant() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP PassThrough {
+            IN in[16];
+            OUT out[16];
+            
+            PARTS:
+            And16(a=in, b=true, out=out);
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    
+    chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0xA5C3);
+    chip.eval().unwrap();
+    assert_eq!(chip.get_pin("out").unwrap().borrow().bus_voltage(), 0xA5C3);
+    
+    // Constants are re-driven after a reset
+    chip.reset().unwrap();
+    chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x00FF);
+    chip.eval().unwrap();
+    assert_eq!(chip.get_pin("out").unwrap().borrow().bus_voltage(), 0x00FF);
+}
+
+#[test]
+fn test_built_composite_numeric_literal() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP Literals {
+            IN in[16];
+            OUT low[16], wide[16];
+            
+            PARTS:
+            Or16(a[0..3]=%B1010, b=false, out=low);
+            Or16(a=%X1F, b=in, out=wide);
+        }
+    "#;
+    
+    let hdl_chip = parser.parse(hdl).unwrap();
+    assert_eq!(hdl_chip.parts[0].connections[0].from, WireSide::Constant { value: 0b1010, width: 4, boolean: false });
+    
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    chip.set_pin("in", 0x0100).unwrap();
+    chip.eval().unwrap();
+    assert_eq!(chip.read_pin("low").unwrap(), 0b1010);
+    assert_eq!(chip.read_pin("wide").unwrap(), 0x011F, "literal is zero-extended to the pin width");
+    
+    // A literal wider than the slice it drives is rejected
+    let too_wide = hdl.replace("a[0..3]=%B1010", "a[0..1]=%B1010");
+    let hdl_chip = parser.parse(&too_wide).unwrap();
+    let err = match builder.build_chip(&hdl_chip) {
+        Ok(_) => panic!("a 4-bit literal must not fit a 2-bit slice"),
+        Err(e) => e.to_string(),
+    };
+    assert!(err.contains("Width mismatch"), "{}", err);
+}
+
+#[test]
+fn test_one_bit_literal_is_zero_extended() {
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP One {
+            OUT literal[16], boolean[16];
+            
+            PARTS:
+            Or16(a=%B1, b=false, out=literal);
+            Or16(a=true, b=false, out=boolean);
+        }
+    "#;
+    
+    let mut chip = ChipBuilder::new().build_chip(&parser.parse(hdl).unwrap()).unwrap();
+    chip.eval().unwrap();
+    
+    // %B1 is the number 1, zero-extended; true drives every bit
+    assert_eq!(chip.read_pin("literal").unwrap(), 0x0001);
+    assert_eq!(chip.read_pin("boolean").unwrap(), 0xFFFF);
+}
+
+#[test]
+fn test_strict_builder_rejects_single_use_internal_pin() {
+    let mut parser = HdlParser::new().unwrap();
+    
+    // `inn` is a typo for `in`: it is read by Not but nothing drives it
+    let hdl = r#"
+        CHIP MyNot {
+            IN in;
+            OUT out;
+            
+            PARTS:
+            Not(in=inn, out=out);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    
+    // Lenient builds keep materializing the stray pin
+    assert!(ChipBuilder::new().build_chip(&hdl_chip).is_ok());
+    
+    let err = ChipBuilder::new().with_strict(true).build_chip(&hdl_chip).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("'inn'"), "error should name the pin: {}", message);
+    assert!(message.contains("never driven"), "error should explain the problem: {}", message);
+}
+
+#[test]
+fn test_strict_builder_accepts_well_formed_chip() {
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP MyNand {
+            IN a, b;
+            OUT out;
+            
+            PARTS:
+            And(a=a, b=b, out=t);
+            Not(in=t, out=out);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    
+    assert!(ChipBuilder::new().with_strict(true).build_chip(&hdl_chip).is_ok());
+}
+
+#[test]
+fn test_builder_preloaded_rom_part() {
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP Program {
+            IN address[15];
+            OUT instruction[16];
+            
+            PARTS:
+            ROM32K(address=address, out=instruction);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    
+    let builder = ChipBuilder::new().with_rom_program(vec![0x0005, 0xEC10, 0xE308]);
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    
+    for (address, expected) in [(0u16, 0x0005u16), (1, 0xEC10), (2, 0xE308)] {
+        chip.get_pin("address").unwrap().borrow_mut().set_bus_voltage(address);
+        chip.eval().unwrap();
+        assert_eq!(chip.get_pin("instruction").unwrap().borrow().bus_voltage(), expected);
+    }
+    
+    // A plain builder still produces an empty ROM
+    let mut chip = ChipBuilder::new().build_chip(&hdl_chip).unwrap();
+    chip.get_pin("address").unwrap().borrow_mut().set_bus_voltage(1);
+    chip.eval().unwrap();
+    assert_eq!(chip.get_pin("instruction").unwrap().borrow().bus_voltage(), 0);
+}
+
+#[test]
+fn test_feedback_through_dff_passes_cycle_detection() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    // A 1-bit register: the stored bit loops back through the Mux that selects load
+    let hdl = r#"
+        CHIP FeedbackBit {
+            IN in, load;
+            OUT out;
+            
+            PARTS:
+            Mux(a=q, b=in, sel=load, out=next);
+            DFF(in=next, out=q, out=out);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    
+    // (in, load, out after the clock)
+    for (input, load, expected) in [(1, 0, 0), (1, 1, 1), (0, 0, 1), (0, 1, 0), (1, 0, 0)] {
+        chip.set_pin("in", input).unwrap();
+        chip.set_pin("load", load).unwrap();
+        chip.eval().unwrap();
+        let clocked = chip.as_clocked_mut().expect("composite with a DFF is clocked");
+        clocked.tick(HIGH).unwrap();
+        clocked.tock(LOW).unwrap();
+        chip.eval().unwrap();
+        assert_eq!(chip.read_pin("out").unwrap(), expected, "in={} load={}", input, load);
+    }
+}
+
+#[test]
+fn test_combinational_loop_is_rejected() {
+    let builder = ChipBuilder::new();
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP Ring {
+            IN in;
+            OUT out;
+            
+            PARTS:
+            And(a=in, b=y, out=x);
+            Not(in=x, out=y, out=out);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let err = match builder.build_chip(&hdl_chip) {
+        Ok(_) => panic!("a loop with no clocked part must be rejected"),
+        Err(e) => e.to_string(),
+    };
+    assert!(err.contains("Circular dependency detected: And -> Not -> And"), "{}", err);
+    
+    // Disjoint bits of one bus do not form a loop
+    let hdl = r#"
+        CHIP Chain {
+            IN in;
+            OUT out[2];
+            
+            PARTS:
+            Not(in=out[0], out=out[1]);
+            Not(in=in, out=out[0]);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let mut chip = builder.build_chip(&hdl_chip).unwrap();
+    chip.set_pin("in", 1).unwrap();
+    chip.eval_settle(3).unwrap();
+    assert_eq!(chip.read_pin("out").unwrap(), 0b10);
+}
+
+#[test]
+fn test_lint_flags_unconnected_and_unused_pins() {
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP Sloppy {
+            IN a, b, unused;
+            OUT out, spare;
+            
+            PARTS:
+            And(a=a, b=b, out=ab);
+            Not(in=ab, out=out);
+            Not(in=a, out=dangling);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    assert_eq!(hdl_chip.lint(), vec![
+        LintWarning::UnusedInput("unused".to_string()),
+        LintWarning::UnconnectedOutput("spare".to_string()),
+        LintWarning::UnreadInternal("dangling".to_string()),
+    ]);
+    assert_eq!(LintWarning::UnconnectedOutput("spare".to_string()).to_string(), "Output pin 'spare' is never driven");
+    
+    // It still builds; the lint only warns
+    assert!(ChipBuilder::new().build_chip(&hdl_chip).is_ok());
+    
+    let clean = parser.parse("CHIP Clean { IN in; OUT out; PARTS: Not(in=in, out=out); }").unwrap();
+    assert!(clean.lint().is_empty());
+}
+
+#[test]
+fn test_part_input_connected_twice_is_rejected() {
+    let mut parser = HdlParser::new().unwrap();
+    let builder = ChipBuilder::new();
+    
+    let hdl_chip = parser.parse(r#"
+        CHIP Twice {
+            IN x, y;
+            OUT o;
+            
+            PARTS:
+            And(a=x, a=y, out=o);
+        }
+    "#).unwrap();
+    let error = builder.build_chip(&hdl_chip).expect_err("a connected twice");
+    assert!(matches!(&error, SimulatorError::Wire(WireError::MultipleAssignment { pin_name, .. }) if pin_name == "a"), "{}", error);
+    assert!(error.to_string().contains("part And connects input 'a' more than once"), "{}", error);
+    
+    // Overlapping slices of a bus input are caught too
+    let hdl_chip = parser.parse(r#"
+        CHIP Overlap {
+            IN x[8], y[8];
+            OUT o[16];
+            
+            PARTS:
+            Not16(in[0..7]=x, in[7..14]=y, out=o);
+        }
+    "#).unwrap();
+    assert!(builder.build_chip(&hdl_chip).is_err());
+    
+    // Disjoint slices, and outputs fanning out to several host pins, are fine
+    let hdl_chip = parser.parse(r#"
+        CHIP Fine {
+            IN x[8], y[8];
+            OUT o[16], p[16];
+            
+            PARTS:
+            Not16(in[0..7]=x, in[8..15]=y, out=o, out=p);
+        }
+    "#).unwrap();
+    assert!(builder.build_chip(&hdl_chip).is_ok());
+}
+
+#[test]
+fn test_build_chip_verbose_reports_inferred_widths_and_lints() {
+    let mut parser = HdlParser::new().unwrap();
+    
+    let hdl = r#"
+        CHIP Widen {
+            IN a[16], b[16], unused;
+            OUT out[16];
+            
+            PARTS:
+            And16(a=a, b=b, out=ab);
+            Not16(in=ab, out=out);
+        }
+    "#;
+    let hdl_chip = parser.parse(hdl).unwrap();
+    let (result, diagnostics) = ChipBuilder::new().build_chip_verbose(&hdl_chip);
+    
+    assert!(result.is_ok());
+    assert_eq!(diagnostics, vec![
+        Diagnostic::InferredWidth { chip: "Widen".to_string(), pin: "ab".to_string(), width: 16 },
+        Diagnostic::Lint(LintWarning::UnusedInput("unused".to_string())),
+    ]);
+    assert_eq!(diagnostics[0].to_string(), "Internal pin 'ab' in chip Widen inferred as 16 bit(s) wide");
+    
+    // A failed build still returns the lints
+    let broken = parser.parse(r#"
+        CHIP Broken {
+            IN in, spare;
+            OUT out;
+            
+            PARTS:
+            Nope(in=in, out=out);
+        }
+    "#).unwrap();
+    let (result, diagnostics) = ChipBuilder::new().build_chip_verbose(&broken);
+    assert!(result.is_err());
+    assert_eq!(diagnostics, vec![Diagnostic::Lint(LintWarning::UnusedInput("spare".to_string()))]);
+}
+
+#[test]
+fn test_register_from_dffs_is_clocked_through_the_library() {
+    let mut parser = HdlParser::new().unwrap();
+    let bit = parser.parse(r#"
+        CHIP Bit {
+            IN in, load;
+            OUT out;
+            
+            PARTS:
+            Mux(a=q, b=in, sel=load, out=next);
+            DFF(in=next, out=q, out=out);
+        }
+    "#).unwrap();
+    let register = parser.parse(r#"
+        CHIP Register2 {
+            IN in[2], load;
+            OUT out[2];
+            
+            PARTS:
+            Bit(in=in[0], load=load, out=out[0]);
+            Bit(in=in[1], load=load, out=out[1]);
+        }
+    "#).unwrap();
+    
+    // Both Bits resolve to the HDL definition above, each holding its own DFF
+    let builder = ChipBuilder::new().with_library(vec![bit]);
+    let mut chip = builder.build_chip(&register).unwrap();
+    
+    // (in, load, out after the clock)
+    let mut previous = 0;
+    for (input, load, expected) in [(2, 1, 2), (1, 0, 2), (1, 1, 1), (3, 1, 3), (0, 0, 3)] {
+        chip.set_pin("in", input).unwrap();
+        chip.set_pin("load", load).unwrap();
+        chip.eval().unwrap();
+        let clocked = chip.as_clocked_mut().expect("register built from DFFs is clocked");
+        clocked.tick(HIGH).unwrap();
+        assert_eq!(chip.read_pin("out").unwrap(), previous, "out holds until the falling edge");
+        chip.as_clocked_mut().unwrap().tock(LOW).unwrap();
+        chip.eval().unwrap();
+        assert_eq!(chip.read_pin("out").unwrap(), expected, "in={} load={}", input, load);
+        previous = expected;
+    }
+}
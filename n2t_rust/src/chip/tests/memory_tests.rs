@@ -1,9 +1,19 @@
 // Tests for memory chips (RAM hierarchy)
 // Translated from TypeScript memory tests and sequential logic
 
+use crate::chip::ChipInterface;
+use crate::chip::{Memory, Ram8Chip, Ram64Chip, SyncRam64Chip, Ram512Chip, Ram4kChip, Ram16kChip, ScreenChip};
+use crate::chip::builtins::mask_address;
 use crate::chip::builder::ChipBuilder;
 use crate::chip::pin::{HIGH, LOW};
 
+// RAM writes only happen on the clock edge, never in eval
+fn clock_cycle(chip: &mut dyn ChipInterface) {
+    let clocked = chip.as_clocked_mut().expect("memory chips are clocked");
+    clocked.tick(HIGH).unwrap();
+    clocked.tock(LOW).unwrap();
+}
+
 #[test]
 fn test_ram8_basic_operations() {
     let builder = ChipBuilder::new();
@@ -18,8 +28,8 @@ fn test_ram8_basic_operations() {
         ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
         ram8.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
         
-        // Evaluate the RAM
-        ram8.eval().unwrap();
+        // Clock the write in
+        clock_cycle(ram8.as_mut());
         
         // Read back the value
         ram8.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
@@ -42,7 +52,7 @@ fn test_ram8_address_isolation() {
         ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr as u16);
         ram8.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
         
-        ram8.eval().unwrap();
+        clock_cycle(ram8.as_mut());
     }
     
     // Verify each address contains the correct value
@@ -72,7 +82,7 @@ fn test_ram64_capacity() {
         ram64.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
         ram64.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
         
-        ram64.eval().unwrap();
+        clock_cycle(ram64.as_mut());
         
         // Read back
         ram64.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
@@ -98,7 +108,7 @@ fn test_ram512_large_capacity() {
         ram512.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
         ram512.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
         
-        ram512.eval().unwrap();
+        clock_cycle(ram512.as_mut());
         
         // Read back
         ram512.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
@@ -124,7 +134,7 @@ fn test_ram4k_addressing() {
         ram4k.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
         ram4k.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
         
-        ram4k.eval().unwrap();
+        clock_cycle(ram4k.as_mut());
         
         // Read back
         ram4k.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
@@ -150,7 +160,7 @@ fn test_ram16k_max_capacity() {
         ram16k.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
         ram16k.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
         
-        ram16k.eval().unwrap();
+        clock_cycle(ram16k.as_mut());
         
         // Read back
         ram16k.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
@@ -170,13 +180,13 @@ fn test_memory_load_control() {
     ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(0);
     ram8.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
     
-    ram8.eval().unwrap();
+    clock_cycle(ram8.as_mut());
     
     // Change input but disable load - value should not change
     ram8.get_pin("in").unwrap().borrow_mut().set_bus_voltage(2000);
     ram8.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
     
-    ram8.eval().unwrap();
+    clock_cycle(ram8.as_mut());
     
     // Read back - should still be original value
     ram8.eval().unwrap();
@@ -195,7 +205,7 @@ fn test_memory_reset() {
         ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
         ram8.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
         
-        ram8.eval().unwrap();
+        clock_cycle(ram8.as_mut());
     }
     
     // Reset should clear all memory
@@ -223,7 +233,7 @@ fn test_memory_concurrent_access() {
     ram64.get_pin("address").unwrap().borrow_mut().set_bus_voltage(10);
     ram64.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
     
-    ram64.eval().unwrap();
+    clock_cycle(ram64.as_mut());
     
     // Change to read from address 20 (different address)
     ram64.get_pin("address").unwrap().borrow_mut().set_bus_voltage(20);
@@ -267,7 +277,7 @@ fn test_memory_address_decoding() {
         ram64.get_pin("address").unwrap().borrow_mut().set_bus_voltage(addr);
         ram64.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
         
-        ram64.eval().unwrap();
+        clock_cycle(ram64.as_mut());
     }
     
     // Verify each address contains its unique value
@@ -282,4 +292,57 @@ fn test_memory_address_decoding() {
         assert_eq!(output, expected_value as u16, 
                   "Address 0b{:06b} should contain {}", addr, expected_value);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_prog_ram_write_port() {
+    let builder = ChipBuilder::new();
+    let mut rom = builder.build_builtin_chip("ProgRAM").unwrap();
+    let program = [(0, 0x0007), (1, 0xEC10), (0x7FFF, 0xBEEF)];
+    
+    // Program each word with a prog edge
+    rom.get_pin("prog").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    for &(address, word) in &program {
+        rom.get_pin("address").unwrap().borrow_mut().set_bus_voltage(address);
+        rom.get_pin("in").unwrap().borrow_mut().set_bus_voltage(word);
+        clock_cycle(rom.as_mut());
+    }
+    
+    // Without prog an edge leaves the contents alone
+    rom.get_pin("prog").unwrap().borrow_mut().pull(LOW, None).unwrap();
+    rom.get_pin("address").unwrap().borrow_mut().set_bus_voltage(0);
+    rom.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0xFFFF);
+    clock_cycle(rom.as_mut());
+    
+    // Reads are combinational, no clock needed
+    for &(address, word) in &program {
+        rom.get_pin("address").unwrap().borrow_mut().set_bus_voltage(address);
+        rom.eval().unwrap();
+        assert_eq!(rom.get_pin("out").unwrap().borrow().bus_voltage(), word, "Word at {}", address);
+    }
+}
+
+// Writing with every address bit set must land in the last word
+fn assert_address_mask_is_size<C: ChipInterface>(mut ram: C, memory: fn(&C) -> &Memory) {
+    let size = memory(&ram).size();
+    let bits = memory(&ram).address_bits();
+    assert_eq!(mask_address(u16::MAX, bits), size - 1, "{}", ram.name());
+    assert_eq!(ram.pin_width("address").unwrap(), bits, "{} address pin", ram.name());
+    
+    ram.set_pin("address", u16::MAX).unwrap();
+    ram.set_pin("in", 0xBEEF).unwrap();
+    ram.set_pin("load", 1).unwrap();
+    clock_cycle(&mut ram);
+    assert_eq!(memory(&ram).get(size - 1), 0xBEEF, "{}", ram.name());
+}
+
+#[test]
+fn test_ram_address_mask_matches_size() {
+    assert_address_mask_is_size(Ram8Chip::new(), Ram8Chip::memory);
+    assert_address_mask_is_size(Ram64Chip::new(), Ram64Chip::memory);
+    assert_address_mask_is_size(SyncRam64Chip::new(), SyncRam64Chip::memory);
+    assert_address_mask_is_size(Ram512Chip::new(), Ram512Chip::memory);
+    assert_address_mask_is_size(Ram4kChip::new(), Ram4kChip::memory);
+    assert_address_mask_is_size(Ram16kChip::new(), Ram16kChip::memory);
+    assert_address_mask_is_size(ScreenChip::new(), ScreenChip::memory);
+}
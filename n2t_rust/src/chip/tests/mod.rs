@@ -9,4 +9,6 @@ pub mod pin_parsing;
 pub mod wire_connections;
 pub mod builder_integration;
 pub mod alu_tests;
-pub mod memory_tests;
\ No newline at end of file
+pub mod memory_tests;
+pub mod composite_chip;
+pub mod waveform;
\ No newline at end of file
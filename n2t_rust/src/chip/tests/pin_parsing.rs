@@ -47,6 +47,11 @@ fn test_pin_range_creation() {
     assert_eq!(range.end, Some(7));
     assert_eq!(range.width(), 8);
     
+    // Inclusive Rust ranges build the same range
+    assert_eq!(PinRange::from_rust("data", 0..=7), range);
+    assert_eq!(PinRange::from(("data".to_string(), 0..=7)), range);
+    assert_eq!(PinRange::from_rust("data", 3..=3), PinRange::new_single_bit("data".to_string(), 3));
+    
     // Test creating a full pin range (no bit specification)
     let range = PinRange::new("input".to_string());
     assert_eq!(range.pin_name, "input");
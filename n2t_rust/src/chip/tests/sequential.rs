@@ -1,10 +1,10 @@
-// Tests for sequential chips (DFF, Bit, Register, PC)
+// Tests for sequential chips (DFF, Bit, Register, PC, Counter)
 // Translated from TypeScript chip.test.ts describe("sequential")
 
 use crate::chip::*;
 use crate::chip::pin::{HIGH, LOW};
 use crate::chip::builder::ChipBuilder;
-use crate::chip::builtins::{DffChip, BitChip, RegisterChip, PcChip, ClockedChip};
+use crate::chip::builtins::{DffChip, BitChip, RegisterChip, EnabledRegisterChip, RegisterNChip, RegisterFileChip, PcChip, CounterChip, ClockedChip};
 use crate::chip::Clock;
 
 #[test]
@@ -133,6 +133,61 @@ fn test_register_from_builder() {
     assert!(register.get_pin("out").is_ok());
 }
 
+#[test]
+fn test_enabled_register_ignores_clock_when_disabled() {
+    let mut register = EnabledRegisterChip::new();
+    
+    // Enabled, it loads like Register
+    register.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0xABCD);
+    register.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    register.get_pin("enable").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    register.tick(HIGH).unwrap();
+    register.tock(LOW).unwrap();
+    assert_eq!(register.get_pin("out").unwrap().borrow().bus_voltage(), 0xABCD);
+    
+    // Disabled, even a loading tick/tock leaves the value alone
+    register.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x1234);
+    register.get_pin("enable").unwrap().borrow_mut().pull(LOW, None).unwrap();
+    register.tick(HIGH).unwrap();
+    register.tock(LOW).unwrap();
+    assert_eq!(register.stored_value(), 0xABCD);
+    assert_eq!(register.get_pin("out").unwrap().borrow().bus_voltage(), 0xABCD);
+    
+    let builder = ChipBuilder::new();
+    let register = builder.build_builtin_chip("EnabledRegister").unwrap();
+    assert!(register.get_pin("enable").is_ok());
+    assert!(ChipBuilder::is_clocked("EnabledRegister"));
+}
+
+#[test]
+fn test_stored_value_is_sampled_on_tick() {
+    let mut dff = DffChip::new();
+    dff.get_pin("in").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    dff.tick(HIGH).unwrap();
+    assert_eq!(dff.stored_value(), HIGH, "DFF samples in on tick");
+    assert_eq!(dff.get_pin("out").unwrap().borrow().voltage(None).unwrap(), LOW, "out waits for tock");
+    dff.tock(LOW).unwrap();
+    assert_eq!(dff.get_pin("out").unwrap().borrow().voltage(None).unwrap(), HIGH);
+    
+    let mut bit = BitChip::new();
+    bit.get_pin("in").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    bit.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    bit.tick(HIGH).unwrap();
+    assert_eq!(bit.stored_value(), HIGH);
+    assert_eq!(bit.read_pin("out").unwrap(), 0);
+    bit.tock(LOW).unwrap();
+    assert_eq!(bit.read_pin("out").unwrap(), 1);
+    
+    let mut register = RegisterChip::new();
+    register.set_pin("in", 0xBEEF).unwrap();
+    register.set_pin("load", 1).unwrap();
+    register.tick(HIGH).unwrap();
+    assert_eq!(register.stored_value(), 0xBEEF);
+    assert_eq!(register.read_pin("out").unwrap(), 0);
+    register.tock(LOW).unwrap();
+    assert_eq!(register.read_pin("out").unwrap(), 0xBEEF);
+}
+
 #[test]
 fn test_pc_increment() {
     let mut pc = PcChip::new();
@@ -226,4 +281,225 @@ fn test_pc_from_builder() {
     assert!(pc.get_pin("inc").is_ok());
     assert!(pc.get_pin("reset").is_ok());
     assert!(pc.get_pin("out").is_ok());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_counter_wraps_with_terminal_count() {
+    let mut counter = CounterChip::new();
+    
+    counter.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0xfffe);
+    counter.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    counter.tick(HIGH).unwrap();
+    counter.tock(LOW).unwrap();
+    assert_eq!(counter.read_pin("out").unwrap(), 0xfffe);
+    assert_eq!(counter.read_pin("tc").unwrap(), 0);
+    
+    counter.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
+    counter.get_pin("enable").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    counter.tick(HIGH).unwrap();
+    counter.tock(LOW).unwrap();
+    assert_eq!(counter.read_pin("out").unwrap(), 0xffff);
+    assert_eq!(counter.read_pin("tc").unwrap(), 1, "tc flags the upcoming wrap");
+    
+    // tc only asserts while counting is enabled
+    counter.get_pin("enable").unwrap().borrow_mut().pull(LOW, None).unwrap();
+    counter.eval().unwrap();
+    assert_eq!(counter.read_pin("tc").unwrap(), 0);
+    counter.tick(HIGH).unwrap();
+    counter.tock(LOW).unwrap();
+    assert_eq!(counter.read_pin("out").unwrap(), 0xffff, "disabled counter holds");
+    
+    counter.get_pin("enable").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    counter.tick(HIGH).unwrap();
+    counter.tock(LOW).unwrap();
+    assert_eq!(counter.read_pin("out").unwrap(), 0);
+    assert_eq!(counter.read_pin("tc").unwrap(), 0);
+}
+
+#[test]
+fn test_counter_priority() {
+    let builder = ChipBuilder::new();
+    let mut chip = builder.build_builtin_chip("Counter").unwrap();
+    assert_eq!(chip.name(), "Counter");
+    
+    // load beats enable
+    chip.set_pin("in", 0x100).unwrap();
+    chip.set_pin("load", 1).unwrap();
+    chip.set_pin("enable", 1).unwrap();
+    let counter = chip.as_clocked_mut().unwrap();
+    counter.tick(HIGH).unwrap();
+    counter.tock(LOW).unwrap();
+    assert_eq!(chip.read_pin("out").unwrap(), 0x100);
+    
+    // reset beats load and enable
+    chip.set_pin("reset", 1).unwrap();
+    let counter = chip.as_clocked_mut().unwrap();
+    counter.tick(HIGH).unwrap();
+    counter.tock(LOW).unwrap();
+    assert_eq!(chip.read_pin("out").unwrap(), 0);
+}
+
+#[test]
+fn test_register_eval_is_side_effect_free() {
+    let mut register = RegisterChip::new();
+    register.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x1234);
+    register.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    
+    let state_before = register.state_hash();
+    
+    // Evaluating without clocking must never latch the input
+    for _ in 0..100 {
+        register.eval().unwrap();
+        let output = register.get_pin("out").unwrap().borrow().bus_voltage();
+        assert_eq!(output, 0, "Register output changed without a clock edge");
+    }
+    assert_eq!(register.state_hash(), state_before);
+    
+    register.tick(HIGH).unwrap();
+    register.tock(LOW).unwrap();
+    let output = register.get_pin("out").unwrap().borrow().bus_voltage();
+    assert_eq!(output, 0x1234);
+}
+
+#[test]
+fn test_ram_eval_does_not_write() {
+    let builder = ChipBuilder::new();
+    let mut ram8 = builder.build_builtin_chip("RAM8").unwrap();
+    
+    ram8.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0xBEEF);
+    ram8.get_pin("address").unwrap().borrow_mut().set_bus_voltage(5);
+    ram8.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    
+    for _ in 0..10 {
+        ram8.eval().unwrap();
+    }
+    let output = ram8.get_pin("out").unwrap().borrow().bus_voltage();
+    assert_eq!(output, 0, "RAM8 eval must not write memory");
+    assert!(ram8.as_clocked_mut().is_some());
+}
+
+#[test]
+fn test_composite_eval_with_register_part() {
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    
+    let builder = ChipBuilder::new();
+    let mut host = Chip::new("Wrapper".to_string());
+    host.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 16))));
+    host.add_input_pin("load".to_string(), Rc::new(RefCell::new(Bus::new("load".to_string(), 1))));
+    host.add_output_pin("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 16))));
+    
+    let register = builder.build_builtin_chip("Register").unwrap();
+    host.wire(register, vec![
+        Connection::new(PinSide::new("in".to_string()), PinSide::new("in".to_string())),
+        Connection::new(PinSide::new("load".to_string()), PinSide::new("load".to_string())),
+        Connection::new(PinSide::new("out".to_string()), PinSide::new("out".to_string())),
+    ]).unwrap();
+    
+    host.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x00FF);
+    host.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    
+    // Debug builds assert the register's state is untouched by each eval
+    for _ in 0..50 {
+        host.eval().unwrap();
+    }
+    let output = host.get_pin("out").unwrap().borrow().bus_voltage();
+    assert_eq!(output, 0);
+}
+
+#[test]
+fn test_register_n_8bit() {
+    let mut register = RegisterNChip::new(8).unwrap();
+    assert_eq!(register.name(), "Register8");
+    assert_eq!(register.get_pin("in").unwrap().borrow().width(), 8);
+    assert_eq!(register.get_pin("out").unwrap().borrow().width(), 8);
+    
+    register.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0xAB);
+    register.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    register.tick(HIGH).unwrap();
+    register.tock(LOW).unwrap();
+    assert_eq!(register.get_pin("out").unwrap().borrow().bus_voltage(), 0xAB);
+    
+    // Holds its value while load is LOW
+    register.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x12);
+    register.get_pin("load").unwrap().borrow_mut().pull(LOW, None).unwrap();
+    register.tick(HIGH).unwrap();
+    register.tock(LOW).unwrap();
+    assert_eq!(register.get_pin("out").unwrap().borrow().bus_voltage(), 0xAB);
+}
+
+#[test]
+fn test_register_n_4bit_wraps() {
+    let builder = ChipBuilder::new();
+    let mut register = builder.build_builtin_chip("Register4").unwrap();
+    assert_eq!(register.name(), "Register4");
+    
+    // Only the low 4 bits of a wider value are stored
+    register.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0x1F);
+    register.get_pin("load").unwrap().borrow_mut().pull(HIGH, None).unwrap();
+    let clocked = register.as_clocked_mut().unwrap();
+    clocked.tick(HIGH).unwrap();
+    clocked.tock(LOW).unwrap();
+    assert_eq!(register.get_pin("out").unwrap().borrow().bus_voltage(), 0xF);
+}
+
+#[test]
+fn test_register_n_invalid_width() {
+    assert!(RegisterNChip::new(0).is_err());
+    assert!(RegisterNChip::new(17).is_err());
+    assert!(ChipBuilder::new().build_builtin_chip("Register17").is_err());
+}
+
+#[test]
+fn test_register_file_write_and_read_ports() {
+    let mut file = RegisterFileChip::new(6).unwrap();
+    assert_eq!(file.name(), "RegisterFile6");
+    assert_eq!(file.pin_width("writeAddr").unwrap(), 3);
+    assert_eq!(file.pin_width("readAddr").unwrap(), 3);
+    
+    let values = [(0, 0x1111), (2, 0x2222), (5, 0xBEEF)];
+    file.set_pin("load", 1).unwrap();
+    for &(address, value) in &values {
+        file.set_pin("writeAddr", address).unwrap();
+        file.set_pin("in", value).unwrap();
+        file.tick(HIGH).unwrap();
+        file.tock(LOW).unwrap();
+    }
+    file.set_pin("load", 0).unwrap();
+    
+    // Reads are combinational and independent of the write address
+    file.set_pin("writeAddr", 0).unwrap();
+    for (address, expected) in [(5, 0xBEEF), (0, 0x1111), (1, 0), (2, 0x2222), (7, 0)] {
+        file.set_pin("readAddr", address).unwrap();
+        file.eval().unwrap();
+        assert_eq!(file.read_pin("out").unwrap(), expected, "register {}", address);
+    }
+    
+    // Built by name, sized by the suffix
+    let built = ChipBuilder::new().build_builtin_chip("RegisterFile16").unwrap();
+    assert_eq!(built.pin_width("readAddr").unwrap(), 4);
+    assert!(RegisterFileChip::new(1).is_err());
+}
+
+#[test]
+fn test_clock_cycle_drives_clocked_and_combinational_chips() {
+    let builder = ChipBuilder::new();
+    let mut clock = Clock::new();
+    
+    // A DFF passes its input through only once the cycle completes
+    let mut dff = builder.build_builtin_chip("DFF").unwrap();
+    dff.set_pin("in", 1).unwrap();
+    assert_eq!(dff.read_pin("out").unwrap(), 0);
+    clock_cycle(dff.as_mut(), &mut clock).unwrap();
+    assert_eq!(dff.read_pin("out").unwrap(), 1);
+    assert_eq!(clock.ticks(), 2);
+    assert_eq!(clock.level(), LOW);
+    
+    // An And has nothing to clock and is just evaluated, but time still advances
+    let mut and = builder.build_builtin_chip("And").unwrap();
+    and.set_pin("a", 1).unwrap();
+    and.set_pin("b", 1).unwrap();
+    clock_cycle(and.as_mut(), &mut clock).unwrap();
+    assert_eq!(and.read_pin("out").unwrap(), 1);
+    assert_eq!(clock.ticks(), 4);
+}
@@ -0,0 +1,60 @@
+// Tests for VCD waveform capture
+// Covers header layout and per-edge value changes of a clocked run
+
+use crate::chip::builder::ChipBuilder;
+use crate::chip::waveform::WaveformRecorder;
+
+// Identifier code the VCD header assigned to `signal`
+fn signal_id(vcd: &str, signal: &str) -> String {
+    vcd.lines()
+        .find_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["$var", "wire", _, id, name, "$end"] if *name == signal => Some(id.to_string()),
+                _ => None,
+            }
+        })
+        .unwrap_or_else(|| panic!("signal {} not declared in:\n{}", signal, vcd))
+}
+
+#[test]
+fn test_vcd_records_pc_run() {
+    let builder = ChipBuilder::new();
+    let mut pc = builder.build_builtin_chip("PC").unwrap();
+    pc.set_pin("inc", 1).unwrap();
+    
+    let vcd = WaveformRecorder::new(pc.as_mut()).record(4).unwrap();
+    
+    assert!(vcd.contains("$scope module PC $end"));
+    assert!(vcd.contains("$var wire 16 "), "16-bit buses declared with their width");
+    assert!(vcd.contains("$enddefinitions $end"));
+    
+    let out = signal_id(&vcd, "out");
+    let clk = signal_id(&vcd, "clk");
+    
+    // out counts up once per rising edge and is quiet on falling edges
+    let changes: Vec<(u32, String)> = {
+        let mut time = 0;
+        let mut changes = Vec::new();
+        for line in vcd.lines() {
+            if let Some(stamp) = line.strip_prefix('#') {
+                time = stamp.parse().unwrap();
+            } else if line.ends_with(&format!(" {}", out)) {
+                changes.push((time, line.to_string()));
+            }
+        }
+        changes
+    };
+    let expected: Vec<(u32, String)> = vec![
+        (0, format!("b0 {}", out)),
+        (1, format!("b1 {}", out)),
+        (3, format!("b10 {}", out)),
+        (5, format!("b11 {}", out)),
+        (7, format!("b100 {}", out)),
+    ];
+    assert_eq!(changes, expected);
+    
+    // The clock toggles every time step
+    assert!(vcd.contains(&format!("#1\n1{}", clk)));
+    assert!(vcd.contains(&format!("#8\n0{}", clk)));
+}
@@ -2,7 +2,7 @@
 // Covers complex wiring patterns, bus connections, and error handling
 
 use crate::chip::*;
-use crate::chip::pin::{HIGH, LOW};
+use crate::chip::pin::{ConstantPin, HIGH, LOW};
 use crate::chip::builder::ChipBuilder;
 use crate::chip::subbus::{PinRange, create_input_subbus, create_output_subbus};
 use std::rc::Rc;
@@ -270,6 +270,27 @@ fn test_pin_range_wire_connection() {
     assert_eq!(output, 0b101);
 }
 
+#[test]
+fn test_range_shorthand_wire_connection() {
+    // The same 3-bit inverter as above, wired through the PinSide range shorthands
+    let mut host_chip = Chip::new("TestChip".to_string());
+    host_chip.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 3))));
+    host_chip.add_output_pin("out".to_string(), Rc::new(RefCell::new(Bus::new("out".to_string(), 3))));
+    
+    let builder = ChipBuilder::new();
+    for bit in 0..3 {
+        let connections = vec![
+            Connection::new(PinSide::bit("in", bit), PinSide::from("in")),
+            Connection::new(PinSide::bits("out", bit..=bit), PinSide::from("out")),
+        ];
+        host_chip.wire(builder.build_builtin_chip("Not").unwrap(), connections).unwrap();
+    }
+    
+    host_chip.get_pin("in").unwrap().borrow_mut().set_bus_voltage(0b101);
+    host_chip.eval().unwrap();
+    assert_eq!(host_chip.get_pin("out").unwrap().borrow().bus_voltage(), 0b010);
+}
+
 #[test]
 fn test_width_mismatch_error() {
     // Test that width mismatches are detected
@@ -301,6 +322,60 @@ fn test_width_mismatch_error() {
     }
 }
 
+#[test]
+fn test_width_mismatch_names_both_pins() {
+    let mut host_chip = Chip::new("TestChip".to_string());
+    host_chip.add_input_pin("a".to_string(), Rc::new(RefCell::new(Bus::new("a".to_string(), 8))));
+    host_chip.add_output_pin("wide".to_string(), Rc::new(RefCell::new(Bus::new("wide".to_string(), 16))));
+    
+    // Host input into a part input: in=a
+    let not_part = ChipBuilder::new().build_builtin_chip("Not").unwrap();
+    let err = host_chip.wire(not_part, vec![Connection::new(PinSide::from("a"), PinSide::from("in"))]).unwrap_err();
+    match &err {
+        WireError::WidthMismatch { from_pin, from_width, to_pin, to_width, connection } => {
+            assert_eq!((from_pin.as_str(), *from_width), ("a", 8));
+            assert_eq!((to_pin.as_str(), *to_width), ("in", 1));
+            assert_eq!(connection, "in=a");
+        }
+        other => panic!("Expected WidthMismatch, got {:?}", other),
+    }
+    assert_eq!(
+        err.to_string(),
+        "Width mismatch in connection 'in=a': host pin 'a' (width 8) != part pin 'in' (width 1)"
+    );
+    
+    // Part output into a host slice: out=wide[0..3] still lists the host side first
+    let not_part = ChipBuilder::new().build_builtin_chip("Not").unwrap();
+    let err = host_chip.wire(not_part, vec![Connection::new(PinSide::bits("wide", 0..=3), PinSide::from("out"))]).unwrap_err();
+    match err {
+        WireError::WidthMismatch { from_pin, from_width, to_pin, to_width, .. } => {
+            assert_eq!((from_pin.as_str(), from_width), ("wide[0..3]", 4));
+            assert_eq!((to_pin.as_str(), to_width), ("out", 1));
+        }
+        other => panic!("Expected WidthMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_wire_error_propagates_as_simulator_error() {
+    fn build(host_chip: &mut Chip) -> crate::error::Result<()> {
+        let not_part = ChipBuilder::new().build_builtin_chip("Not")?;
+        let connections = vec![Connection::new(PinSide::new("in".to_string()), PinSide::new("in".to_string()))];
+        host_chip.wire_checked(not_part, connections)?;
+        Ok(())
+    }
+    
+    let mut host_chip = Chip::new("TestChip".to_string());
+    host_chip.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 8))));
+    
+    let err = build(&mut host_chip).unwrap_err();
+    assert!(matches!(
+        err,
+        crate::error::SimulatorError::Wire(WireError::WidthMismatch { from_width: 8, to_width: 1, .. })
+    ));
+    assert!(err.to_string().contains("Width mismatch"), "{}", err);
+}
+
 #[test]
 fn test_bus_voltage_masking() {
     // Test that SubBus properly masks values to fit target width
@@ -385,4 +460,48 @@ fn test_multi_part_composite_chip() {
     host_chip.eval().unwrap();
     let output = host_chip.get_pin("out").unwrap().borrow().voltage(None).unwrap();
     assert_eq!(output, HIGH);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_subbus_listing_names_the_connection() {
+    let mut host_chip = Chip::new("Widen".to_string());
+    host_chip.add_input_pin("in".to_string(), Rc::new(RefCell::new(Bus::new("in".to_string(), 8))));
+    host_chip.add_input_pin("hi".to_string(), Rc::new(RefCell::new(Bus::new("hi".to_string(), 16))));
+    host_chip.add_output_pin("wide".to_string(), Rc::new(RefCell::new(Bus::new("wide".to_string(), 16))));
+    
+    let not8 = ChipBuilder::new().build_builtin_chip("Not8").unwrap();
+    host_chip.wire(not8, vec![
+        Connection::new(PinSide::from("in"), PinSide::from("in")),
+        Connection::new(PinSide::bits("wide", 0..=7), PinSide::from("out")),
+    ]).unwrap();
+    let not = ChipBuilder::new().build_builtin_chip("Not").unwrap();
+    host_chip.wire(not, vec![
+        Connection::new(PinSide::bit("hi", 15), PinSide::from("in")),
+        Connection::new(PinSide::bit("wide", 15), PinSide::from("out")),
+    ]).unwrap();
+    
+    // in=in is unranged so it makes no SubBus
+    assert_eq!(host_chip.subbus_listing(), vec![
+        "SubBus wide[0..7] from connection out=wide[0..7]",
+        "SubBus hi[15..15] from connection in=hi[15]",
+        "SubBus wide[15..15] from connection out=wide[15]",
+    ]);
+}
+
+#[test]
+fn test_wired_constant_is_read_only() {
+    let builder = ChipBuilder::new();
+    let mut not16 = builder.build_builtin_chip("Not16").unwrap();
+    let constant: Rc<RefCell<dyn Pin>> = Rc::new(RefCell::new(ConstantPin::with_value("%B0101".to_string(), 0b0101, 16).unwrap()));
+    constant.borrow_mut().connect(Rc::downgrade(&not16.get_pin("in").unwrap()));
+    
+    not16.eval().unwrap();
+    assert_eq!(not16.read_pin("out").unwrap(), !0b0101);
+    
+    // Writes to the constant are dropped, so the part keeps reading the literal
+    constant.borrow_mut().set_bus_voltage(0xffff);
+    constant.borrow_mut().pull(HIGH, Some(1)).unwrap();
+    not16.eval().unwrap();
+    assert_eq!(constant.borrow().bus_voltage(), 0b0101);
+    assert_eq!(not16.read_pin("out").unwrap(), !0b0101);
+}
@@ -20,6 +20,11 @@ pub enum SimulatorError {
     #[error("Test error: {0}")]
     Test(String),
     
+    #[error("Test exceeded its budget of {budget} instructions")]
+    Timeout {
+        budget: usize,
+    },
+    
     #[error("VM error: {0}")]
     Vm(String),
     
@@ -31,6 +36,50 @@ pub enum SimulatorError {
         pin: String,
         chip: String,
     },
+    
+    #[error("Input pin '{pin}' of chip '{chip}' is floating (never driven)")]
+    FloatingInput {
+        pin: String,
+        chip: String,
+    },
+    
+    #[error("Pin '{pin}' was borrowed during propagation and missed an update")]
+    BorrowConflict {
+        pin: String,
+    },
+    
+    #[error("Wiring error: {0}")]
+    Wire(#[from] crate::chip::WireError),
+    
+    #[error("in {}: {}", .path.join(" > "), .source)]
+    InChip {
+        path: Vec<String>,
+        source: Box<SimulatorError>,
+    },
+}
+
+impl SimulatorError {
+    /// Record that this error surfaced while evaluating chip `name`, outermost chip first
+    pub fn in_chip(self, name: &str) -> Self {
+        match self {
+            SimulatorError::InChip { mut path, source } => {
+                path.insert(0, name.to_string());
+                SimulatorError::InChip { path, source }
+            }
+            other => SimulatorError::InChip {
+                path: vec![name.to_string()],
+                source: Box::new(other),
+            },
+        }
+    }
+    
+    /// The underlying error with any chip breadcrumbs stripped
+    pub fn root_cause(&self) -> &SimulatorError {
+        match self {
+            SimulatorError::InChip { source, .. } => source.root_cause(),
+            other => other,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
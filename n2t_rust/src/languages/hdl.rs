@@ -1,37 +1,74 @@
 use crate::error::{Result, SimulatorError};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HdlChip {
     pub name: String,
     pub inputs: Vec<PinDecl>,
     pub outputs: Vec<PinDecl>,
     pub parts: Vec<Part>,
     pub is_builtin: bool,
+    // Builtin named by `BUILTIN <name>;`, when it differs from the chip's own name
+    pub builtin_name: Option<String>,
     pub clocked_pins: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PinDecl {
     pub name: String,
     pub width: Option<u16>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Part {
     pub name: String,
     pub connections: Vec<Wire>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Wire {
     pub from: WireSide,
     pub to: WireSide,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WireSide {
     Pin { name: String, range: Option<crate::chip::subbus::PinRange> },
-    Constant(bool),
+    // `true`/`false` are 1-bit boolean constants that drive every bit they connect to;
+    // numeric literals carry their own width and are zero-extended, even when 1 bit wide
+    Constant { value: u16, width: usize, boolean: bool },
+}
+
+/// Wiring that builds but is probably a mistake
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LintWarning {
+    /// Declared input that no part reads
+    UnusedInput(String),
+    /// Declared output that no part drives
+    UnconnectedOutput(String),
+    /// Internal pin that a part drives but no part reads
+    UnreadInternal(String),
+}
+
+impl std::fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintWarning::UnusedInput(pin) => write!(f, "Input pin '{}' is never read", pin),
+            LintWarning::UnconnectedOutput(pin) => write!(f, "Output pin '{}' is never driven", pin),
+            LintWarning::UnreadInternal(pin) => write!(f, "Internal pin '{}' is driven but never read", pin),
+        }
+    }
+}
+
+impl HdlChip {
+    /// Lint against the default builtin set; see `ChipBuilder::lint`
+    pub fn lint(&self) -> Vec<LintWarning> {
+        crate::chip::builder::ChipBuilder::new().lint(self)
+    }
 }
 
 pub struct HdlParser {
@@ -44,9 +81,24 @@ impl HdlParser {
         Ok(Self {})
     }
     
+    /// Clear all per-parse state, leaving the parser as `new()` made it
+    /// `parse` calls this first, so one instance can be reused across files; any state added to
+    /// the parser must either be reset here or be deliberately shared between parses
+    pub fn reset(&mut self) {
+        // Nothing is carried between parses yet
+    }
+    
+    /// Parse a chip read from any source (file, stdin, socket); the input is buffered whole
+    pub fn parse_reader(&mut self, mut reader: impl std::io::Read) -> Result<HdlChip> {
+        let mut source = String::new();
+        reader.read_to_string(&mut source)?;
+        self.parse(&source)
+    }
+    
     pub fn parse(&mut self, source: &str) -> Result<HdlChip> {
         // Simple parser implementation for HDL
         // This is a placeholder that recognizes basic HDL structure
+        self.reset();
         
         let lines: Vec<&str> = source.lines()
             .map(|line| line.trim())
@@ -67,8 +119,14 @@ impl HdlParser {
         
         let name = chip_line[5..].trim_end_matches(" {").trim().to_string();
         
-        // Look for BUILTIN
-        let is_builtin = lines.iter().any(|line| line.trim() == "BUILTIN;");
+        // Look for BUILTIN, optionally naming the builtin that implements the chip
+        let builtin_line = lines.iter()
+            .find_map(|line| line.strip_prefix("BUILTIN").and_then(|rest| rest.strip_suffix(';')))
+            .map(str::trim);
+        let is_builtin = builtin_line.is_some();
+        let builtin_name = builtin_line
+            .filter(|rest| !rest.is_empty())
+            .map(str::to_string);
         
         // Parse input pins
         let inputs = self.parse_pin_section(&lines, "IN")?;
@@ -92,6 +150,7 @@ impl HdlParser {
             outputs,
             parts,
             is_builtin,
+            builtin_name,
             clocked_pins,
         })
     }
@@ -275,10 +334,18 @@ impl HdlParser {
         
         // Check for boolean constants
         if side == "true" || side == "1" {
-            return Ok(WireSide::Constant(true));
+            return Ok(WireSide::Constant { value: 1, width: 1, boolean: true });
         }
         if side == "false" || side == "0" {
-            return Ok(WireSide::Constant(false));
+            return Ok(WireSide::Constant { value: 0, width: 1, boolean: true });
+        }
+        
+        // Then numeric literals such as %B1010, %X1F or 12
+        if let Some((value, width)) = crate::chip::pin::parse_constant_literal(side) {
+            return Ok(WireSide::Constant { value, width, boolean: false });
+        }
+        if side.starts_with('%') {
+            return Err(SimulatorError::Parse(format!("Invalid numeric literal '{}'", side)));
         }
         
         // Parse pin with optional range
@@ -424,9 +491,137 @@ mod tests {
         
         // Test constants
         let wire_side = parser.parse_wire_side("true").unwrap();
-        assert!(matches!(wire_side, WireSide::Constant(true)));
+        assert!(matches!(wire_side, WireSide::Constant { value: 1, width: 1, boolean: true }));
         
         let wire_side = parser.parse_wire_side("false").unwrap();
-        assert!(matches!(wire_side, WireSide::Constant(false)));
+        assert!(matches!(wire_side, WireSide::Constant { value: 0, width: 1, boolean: true }));
+        
+        // Numeric literals
+        let wire_side = parser.parse_wire_side("%B1010").unwrap();
+        assert_eq!(wire_side, WireSide::Constant { value: 0b1010, width: 4, boolean: false });
+        
+        let wire_side = parser.parse_wire_side("%X1F").unwrap();
+        assert_eq!(wire_side, WireSide::Constant { value: 0x1f, width: 8, boolean: false });
+    }
+    
+    #[test]
+    fn test_constant_wire_sides_carry_value_and_width() {
+        let parser = HdlParser::new().unwrap();
+        
+        assert_eq!(parser.parse_wire_side("true").unwrap(), WireSide::Constant { value: 1, width: 1, boolean: true });
+        assert_eq!(parser.parse_wire_side("false").unwrap(), WireSide::Constant { value: 0, width: 1, boolean: true });
+        assert_eq!(parser.parse_wire_side("%B11").unwrap(), WireSide::Constant { value: 3, width: 2, boolean: false });
+        assert_eq!(parser.parse_wire_side("%B0011").unwrap(), WireSide::Constant { value: 3, width: 4, boolean: false });
+        
+        // Only true/false and bare 1/0 are booleans; a 1-bit literal is a number
+        assert_eq!(parser.parse_wire_side("1").unwrap(), WireSide::Constant { value: 1, width: 1, boolean: true });
+        assert_eq!(parser.parse_wire_side("%B1").unwrap(), WireSide::Constant { value: 1, width: 1, boolean: false });
+        
+        // A bad literal is not a pin name either
+        assert!(parser.parse_wire_side("%B12").is_err());
+    }
+    
+    #[test]
+    fn test_parsed_chips_compare_structurally() {
+        let mut parser = HdlParser::new().unwrap();
+        
+        let hdl = r#"
+            CHIP Not2 {
+                IN in[2];
+                OUT out[2];
+                PARTS:
+                Not(in=in[0], out=out[0]);
+                Not(in=in[1], out=out[1]);
+            }
+        "#;
+        let first = parser.parse(hdl).unwrap();
+        let second = parser.parse(hdl).unwrap();
+        assert_eq!(first, second);
+        
+        // A different bit range on one wire is a different chip
+        let swapped = parser.parse(&hdl.replace("Not(in=in[1], out=out[1])", "Not(in=in[1], out=out[0])")).unwrap();
+        assert_ne!(first, swapped);
+        
+        // Constants compare by value
+        assert_eq!(parser.parse_wire_side("true").unwrap(), WireSide::Constant { value: 1, width: 1, boolean: true });
+        assert_ne!(parser.parse_wire_side("true").unwrap(), WireSide::Constant { value: 0, width: 1, boolean: true });
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_parse_reader() {
+        let mut parser = HdlParser::new().unwrap();
+        
+        let hdl = r#"
+            CHIP Not {
+                IN in;
+                OUT out;
+                BUILTIN;
+            }
+        "#;
+        let from_reader = parser.parse_reader(std::io::Cursor::new(hdl)).unwrap();
+        assert_eq!(from_reader, parser.parse(hdl).unwrap());
+        assert_eq!(from_reader.name, "Not");
+        
+        // Invalid UTF-8 surfaces as an I/O error rather than a panic
+        let result = parser.parse_reader(std::io::Cursor::new(vec![0xff, 0xfe]));
+        assert!(matches!(result, Err(SimulatorError::Io(_))));
+    }
+    
+    #[test]
+    fn test_parser_reuse_keeps_chips_independent() {
+        let mut parser = HdlParser::new().unwrap();
+        
+        let and_hdl = r#"
+            CHIP And {
+                IN a, b;
+                OUT out;
+                PARTS:
+                Nand(a=a, b=b, out=nab);
+                Not(in=nab, out=out);
+            }
+        "#;
+        let mux_hdl = r#"
+            CHIP Mux16 {
+                IN a[16], b[16], sel;
+                OUT out[16];
+                BUILTIN;
+            }
+        "#;
+        
+        let and_chip = parser.parse(and_hdl).unwrap();
+        let mux_chip = parser.parse(mux_hdl).unwrap();
+        
+        // Nothing from the first chip leaks into the second
+        assert_eq!(mux_chip.name, "Mux16");
+        assert_eq!(mux_chip.inputs.len(), 3);
+        assert!(mux_chip.parts.is_empty());
+        assert!(mux_chip.is_builtin);
+        
+        // A failed parse in between doesn't disturb later ones either
+        assert!(parser.parse("OUT out;").is_err());
+        parser.reset();
+        
+        assert_eq!(parser.parse(and_hdl).unwrap(), and_chip);
+        assert_eq!(HdlParser::new().unwrap().parse(mux_hdl).unwrap(), mux_chip);
+        assert_eq!(and_chip.parts.len(), 2);
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_hdl_chip_json_round_trip() {
+        let mut parser = HdlParser::new().unwrap();
+        
+        let hdl = r#"
+            CHIP Route {
+                IN x[16], sel;
+                OUT y[4];
+                PARTS:
+                Mux4(a=x[0..3], b=%B1010, sel=sel, out=y);
+            }
+        "#;
+        let chip = parser.parse(hdl).unwrap();
+        let json = serde_json::to_string(&chip).unwrap();
+        let restored: HdlChip = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, chip);
+    }
+}
@@ -3,14 +3,26 @@
 
 use crate::chip::ChipInterface;
 use crate::chip::clock::Clock;
-use crate::error::Result;
+use crate::chip::pin::HIGH;
+use crate::error::{Result, SimulatorError};
 
 pub struct ChipTest {
     chip: Option<Box<dyn ChipInterface>>,
     instructions: Vec<Box<dyn TestInstruction>>,
     output_list: Vec<OutputSpec>,
+    // Output table rows as cells, and the same table rendered as text for log()
+    rows: Vec<Vec<String>>,
     log_buffer: String,
     clock: Clock,
+    // Maximum leaf instructions per run (None = unlimited) and the count so far
+    budget: Option<usize>,
+    steps: usize,
+    // Index of the next top-level instruction for step()
+    cursor: usize,
+    // Files named by the script's header: `load`, `output-file` and `compare-to`
+    load_file: Option<String>,
+    output_file: Option<String>,
+    compare_to: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -48,8 +60,31 @@ impl ChipTest {
             chip: None,
             instructions: Vec::new(),
             output_list: Vec::new(),
+            rows: Vec::new(),
             log_buffer: String::new(),
             clock: Clock::new(),
+            budget: None,
+            steps: 0,
+            cursor: 0,
+            load_file: None,
+            output_file: None,
+            compare_to: None,
+        }
+    }
+    
+    /// Fail a run with `SimulatorError::Timeout` once it has executed `max_instructions`
+    /// leaf instructions, so a runaway script errors out instead of hanging
+    pub fn with_budget(mut self, max_instructions: usize) -> Self {
+        self.budget = Some(max_instructions);
+        self
+    }
+    
+    /// Count one executed leaf instruction against the budget
+    pub fn charge_instruction(&mut self) -> Result<()> {
+        self.steps += 1;
+        match self.budget {
+            Some(budget) if self.steps > budget => Err(SimulatorError::Timeout { budget }),
+            _ => Ok(()),
         }
     }
     
@@ -67,22 +102,75 @@ impl ChipTest {
     }
     
     pub async fn run(&mut self) -> Result<()> {
+        self.run_blocking()
+    }
+    
+    /// Run every instruction synchronously; no async runtime is needed
+    pub fn run_blocking(&mut self) -> Result<()> {
+        self.rewind();
+        while self.step()?.is_some() {}
+        Ok(())
+    }
+    
+    /// Execute the next top-level instruction, returning None once none remain
+    pub fn step(&mut self) -> Result<Option<()>> {
+        if self.cursor >= self.instructions.len() {
+            return Ok(None);
+        }
+        
         // Take ownership of instructions to avoid borrowing issues
         let instructions = std::mem::take(&mut self.instructions);
-        for instruction in &instructions {
-            instruction.execute(self)?;
-        }
+        let result = instructions[self.cursor].execute(self);
         // Restore instructions
         self.instructions = instructions;
-        Ok(())
+        self.cursor += 1;
+        result.map(Some)
+    }
+    
+    /// Move back to the first instruction and reset the budget count
+    pub fn rewind(&mut self) {
+        self.cursor = 0;
+        self.steps = 0;
+    }
+    
+    /// Index of the instruction the next step() executes
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+    
+    pub fn instructions(&self) -> &[Box<dyn TestInstruction>] {
+        &self.instructions
     }
     
+    /// The output table as text, one `|`-delimited line per row of rows()
     pub fn log(&self) -> &str {
         &self.log_buffer
     }
     
+    /// The output table so far: one row per `output-list` header and `output`, each cell
+    /// as written between the bars, padding included
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+    
+    pub fn append_row(&mut self, cells: Vec<String>) {
+        self.log_buffer.push_str(&render_row(&cells));
+        self.log_buffer.push('\n');
+        self.rows.push(cells);
+    }
+    
+    /// Append raw log text; each complete `|a|b|` line is also recorded as a row
+    #[deprecated(note = "use append_row, which keeps rows() in step with log()")]
     pub fn append_log(&mut self, text: &str) {
-        self.log_buffer.push_str(text);
+        for line in text.split_inclusive('\n') {
+            let cells = line.strip_suffix('\n')
+                .and_then(|line| line.strip_prefix('|'))
+                .and_then(|line| line.strip_suffix('|'));
+            match cells {
+                Some(cells) => self.append_row(cells.split('|').map(str::to_string).collect()),
+                None => self.log_buffer.push_str(line),
+            }
+        }
     }
     
     pub fn chip(&self) -> Option<&dyn ChipInterface> {
@@ -104,6 +192,33 @@ impl ChipTest {
     pub fn output_specs(&self) -> &[OutputSpec] {
         &self.output_list
     }
+    
+    /// HDL file the script asked to load, e.g. "Not.hdl"
+    pub fn load_file(&self) -> Option<&str> {
+        self.load_file.as_deref()
+    }
+    
+    pub fn set_load_file(&mut self, file: &str) {
+        self.load_file = Some(file.to_string());
+    }
+    
+    /// File the script's output table should be written to, e.g. "Not.out"
+    pub fn output_file(&self) -> Option<&str> {
+        self.output_file.as_deref()
+    }
+    
+    pub fn set_output_file(&mut self, file: &str) {
+        self.output_file = Some(file.to_string());
+    }
+    
+    /// Compare file the output table is checked against, e.g. "Not.cmp"
+    pub fn compare_to(&self) -> Option<&str> {
+        self.compare_to.as_deref()
+    }
+    
+    pub fn set_compare_to(&mut self, file: &str) {
+        self.compare_to = Some(file.to_string());
+    }
 }
 
 impl Default for ChipTest {
@@ -142,6 +257,7 @@ impl TestSetInstruction {
 
 impl TestInstruction for TestSetInstruction {
     fn execute(&self, test: &mut ChipTest) -> Result<()> {
+        test.charge_instruction()?;
         if let Some(chip) = test.chip_mut() {
             // Handle memory operations (like RAM16K)
             if self.pin_name.contains("RAM") || self.pin_name.contains("Memory") {
@@ -164,6 +280,7 @@ pub struct TestEvalInstruction;
 
 impl TestInstruction for TestEvalInstruction {
     fn execute(&self, test: &mut ChipTest) -> Result<()> {
+        test.charge_instruction()?;
         if let Some(chip) = test.chip_mut() {
             chip.eval()?;
         }
@@ -176,16 +293,18 @@ pub struct TestOutputInstruction;
 
 impl TestInstruction for TestOutputInstruction {
     fn execute(&self, test: &mut ChipTest) -> Result<()> {
-        let mut line = String::from("|");
+        test.charge_instruction()?;
+        let mut cells = Vec::with_capacity(test.output_list.len());
         
         for spec in &test.output_list {
             let value = if spec.id == "time" {
-                // Special case for time output
-                format!("{}", test.clock.ticks())
+                // Special case for time output: completed cycles, "+" between tick and tock
+                let suffix = if test.clock.level() == HIGH { "+" } else { "" };
+                format!("{}{}", test.clock.ticks() / 2, suffix)
             } else if let Some(chip) = test.chip() {
                 // Get pin value
                 if let Ok(pin) = chip.get_pin(&spec.id) {
-                    format!("{}", pin.borrow().bus_voltage())
+                    format_value(spec, pin.borrow().bus_voltage())
                 } else {
                     "0".to_string()
                 }
@@ -199,19 +318,69 @@ impl TestInstruction for TestOutputInstruction {
                     // String format with padding
                     format!("{:width$}", value, width = len)
                 } else {
-                    // Numeric format
-                    format!("{:width$}", value, width = len)
+                    // Numeric format, right-aligned between the pads
+                    format!("{}{:>width$}{}",
+                        " ".repeat(spec.lpad.unwrap_or(0)),
+                        value,
+                        " ".repeat(spec.rpad.unwrap_or(0)),
+                        width = len)
                 }
             } else {
                 format!(" {} ", value)
             };
             
-            line.push_str(&formatted);
-            line.push('|');
+            cells.push(formatted);
         }
-        line.push('\n');
         
-        test.append_log(&line);
+        test.append_row(cells);
+        Ok(())
+    }
+}
+
+/// One output table row as a log line, without the newline: `|a|b|`
+pub fn render_row(cells: &[String]) -> String {
+    format!("|{}|", cells.join("|"))
+}
+
+/// Render a pin value in the spec's radix: B binary, X hex, D signed decimal
+/// Binary and hex are zero-padded to `len` digits; anything else is unsigned decimal
+fn format_value(spec: &OutputSpec, value: u16) -> String {
+    match (spec.style.as_deref(), spec.len) {
+        (Some("B"), Some(len)) => {
+            let bits = format!("{:016b}", value);
+            bits[16 - len.min(16)..].to_string()
+        }
+        (Some("X"), Some(len)) => {
+            let digits = format!("{:04X}", value);
+            digits[4 - len.min(4)..].to_string()
+        }
+        (Some("D"), _) => format!("{}", value as i16),
+        _ => format!("{}", value),
+    }
+}
+
+/// `output-list`: install the output columns and write the header row naming them
+#[derive(Debug)]
+pub struct TestOutputListInstruction {
+    specs: Vec<OutputSpec>,
+}
+
+impl TestOutputListInstruction {
+    pub fn new(specs: Vec<OutputSpec>) -> Self {
+        Self { specs }
+    }
+}
+
+impl TestInstruction for TestOutputListInstruction {
+    fn execute(&self, test: &mut ChipTest) -> Result<()> {
+        test.charge_instruction()?;
+        let cells = self.specs.iter().map(|spec| {
+            let width = spec.lpad.unwrap_or(1) + spec.len.unwrap_or(spec.id.len()) + spec.rpad.unwrap_or(1);
+            format!("{:^width$}", spec.id, width = width)
+        }).collect();
+        
+        test.output_list(self.specs.clone());
+        test.append_row(cells);
         Ok(())
     }
 }
@@ -221,11 +390,16 @@ pub struct TestTickInstruction;
 
 impl TestInstruction for TestTickInstruction {
     fn execute(&self, test: &mut ChipTest) -> Result<()> {
+        test.charge_instruction()?;
         test.clock_mut().tick()?;
+        let level = test.clock().level();
         
-        // For time output, append "+" to indicate tick phase
-        if test.output_specs().iter().any(|spec| spec.id == "time") {
-            // This is handled in the output formatting
+        // Settle inputs, then let a clocked chip sample them on the rising edge
+        if let Some(chip) = test.chip_mut() {
+            chip.eval()?;
+            if let Some(clocked) = chip.as_clocked_mut() {
+                clocked.tick(level)?;
+            }
         }
         
         Ok(())
@@ -237,7 +411,18 @@ pub struct TestTockInstruction;
 
 impl TestInstruction for TestTockInstruction {
     fn execute(&self, test: &mut ChipTest) -> Result<()> {
+        test.charge_instruction()?;
         test.clock_mut().tick()?;  // Complete the clock cycle
+        let level = test.clock().level();
+        
+        // Falling edge publishes the sampled state, then outputs settle
+        if let Some(chip) = test.chip_mut() {
+            if let Some(clocked) = chip.as_clocked_mut() {
+                clocked.tock(level)?;
+            }
+            chip.eval()?;
+        }
+        
         Ok(())
     }
 }
@@ -274,4 +459,61 @@ impl Default for TestCompoundInstruction {
     }
 }
 
+/// Condition of a `while` loop: `<pin> <op> <value>` with op one of = <> < <= > >=
+#[derive(Debug, Clone)]
+pub struct TestCondition {
+    pub pin: String,
+    pub op: String,
+    pub value: u16,
+}
+
+impl TestCondition {
+    pub fn new(pin: &str, op: &str, value: u16) -> Self {
+        Self {
+            pin: pin.to_string(),
+            op: op.to_string(),
+            value,
+        }
+    }
+    
+    fn evaluate(&self, test: &ChipTest) -> Result<bool> {
+        let chip = test.chip().ok_or_else(|| SimulatorError::Test("No chip loaded".to_string()))?;
+        let actual = chip.get_pin(&self.pin)?.borrow().bus_voltage();
+        match self.op.as_str() {
+            "=" => Ok(actual == self.value),
+            "<>" => Ok(actual != self.value),
+            "<" => Ok(actual < self.value),
+            "<=" => Ok(actual <= self.value),
+            ">" => Ok(actual > self.value),
+            ">=" => Ok(actual >= self.value),
+            op => Err(SimulatorError::Test(format!("Unknown comparison operator '{}'", op))),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TestWhileInstruction {
+    condition: TestCondition,
+    body: TestCompoundInstruction,
+}
+
+impl TestWhileInstruction {
+    pub fn new(condition: TestCondition, body: TestCompoundInstruction) -> Self {
+        Self { condition, body }
+    }
+}
+
+impl TestInstruction for TestWhileInstruction {
+    fn execute(&self, test: &mut ChipTest) -> Result<()> {
+        // Each condition check is charged too, so even an empty body can't spin forever
+        loop {
+            test.charge_instruction()?;
+            if !self.condition.evaluate(test)? {
+                return Ok(());
+            }
+            self.body.execute(test)?;
+        }
+    }
+}
+
 // Tests for this module are in separate chiptst_tests.rs file
\ No newline at end of file
@@ -126,13 +126,7 @@ mod chip_test_framework {
         }
         
         // Run the test
-        let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build()
-            .unwrap();
-        rt.block_on(async {
-            test.run().await.unwrap();
-        });
+        test.run_blocking().unwrap();
         
         // The output should show clock progression
         // Note: Our simplified implementation may not match exactly,
@@ -147,6 +141,45 @@ mod chip_test_framework {
         }
     }
     
+    #[test]
+    fn test_clock_drives_dff() {
+        let builder = ChipBuilder::new();
+        let dff = builder.build_builtin_chip("DFF").unwrap();
+        
+        let mut test = ChipTest::new().with_chip(dff);
+        test.output_list(vec![
+            OutputSpec { id: "time".to_string(), ..Default::default() },
+            OutputSpec { id: "in".to_string(), ..Default::default() },
+            OutputSpec { id: "out".to_string(), ..Default::default() },
+        ]);
+        
+        // in=1: out follows only once the cycle completes
+        let mut statement = TestCompoundInstruction::new();
+        statement.add_instruction(Box::new(TestSetInstruction::new("in", 1)));
+        statement.add_instruction(Box::new(TestTickInstruction));
+        statement.add_instruction(Box::new(TestOutputInstruction));
+        statement.add_instruction(Box::new(TestTockInstruction));
+        statement.add_instruction(Box::new(TestOutputInstruction));
+        test.add_instruction(Box::new(statement));
+        
+        // in=0: out drops after the next cycle
+        let mut statement = TestCompoundInstruction::new();
+        statement.add_instruction(Box::new(TestSetInstruction::new("in", 0)));
+        statement.add_instruction(Box::new(TestTickInstruction));
+        statement.add_instruction(Box::new(TestTockInstruction));
+        statement.add_instruction(Box::new(TestOutputInstruction));
+        test.add_instruction(Box::new(statement));
+        
+        test.run_blocking().unwrap();
+        
+        let lines: Vec<&str> = test.log().trim().split('\n').collect();
+        assert_eq!(lines, vec![
+            "| 0+ | 1 | 0 |",
+            "| 1 | 1 | 1 |",
+            "| 2 | 0 | 0 |",
+        ]);
+    }
+    
     #[test]
     fn test_basic_test_instructions() {
         // Test individual test instructions work correctly
@@ -192,13 +225,7 @@ mod chip_test_framework {
         test.add_instruction(Box::new(TestOutputInstruction));
         
         // Run the test
-        let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build()
-            .unwrap();
-        rt.block_on(async {
-            test.run().await.unwrap();
-        });
+        test.run_blocking().unwrap();
         
         // Should produce formatted output
         let log = test.log();
@@ -229,4 +256,189 @@ mod chip_test_framework {
             assert_eq!(output, HIGH, "XOR(1, 0) should be 1");
         }
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_budget_stops_runaway_while_loop() {
+        let builder = ChipBuilder::new();
+        let not_chip = builder.build_builtin_chip("Not").unwrap();
+        let mut test = ChipTest::new().with_chip(not_chip).with_budget(100);
+        
+        // while out = 1 { set in 0, eval } - Not(0) keeps out at 1 forever
+        let mut body = TestCompoundInstruction::new();
+        body.add_instruction(Box::new(TestSetInstruction::new("in", 0)));
+        body.add_instruction(Box::new(TestEvalInstruction));
+        test.add_instruction(Box::new(TestEvalInstruction));
+        test.add_instruction(Box::new(TestWhileInstruction::new(TestCondition::new("out", "=", 1), body)));
+        
+        let result = test.run_blocking();
+        
+        assert!(matches!(result, Err(crate::error::SimulatorError::Timeout { budget: 100 })), "got {:?}", result);
+    }
+    
+    #[test]
+    fn test_while_loop_terminates_within_budget() {
+        let builder = ChipBuilder::new();
+        let not_chip = builder.build_builtin_chip("Not").unwrap();
+        let mut test = ChipTest::new().with_chip(not_chip).with_budget(100);
+        
+        // The body flips out to 0, so the loop runs once
+        let mut body = TestCompoundInstruction::new();
+        body.add_instruction(Box::new(TestSetInstruction::new("in", 1)));
+        body.add_instruction(Box::new(TestEvalInstruction));
+        test.add_instruction(Box::new(TestEvalInstruction));
+        test.add_instruction(Box::new(TestWhileInstruction::new(TestCondition::new("out", "<>", 0), body)));
+        
+        test.run_blocking().unwrap();
+        
+        assert_eq!(test.chip().unwrap().read_pin("out").unwrap(), 0);
+    }
+    
+    #[test]
+    fn test_step_runs_one_instruction_at_a_time() {
+        let builder = ChipBuilder::new();
+        let nand_chip = builder.build_builtin_chip("Nand").unwrap();
+        let mut test = ChipTest::new().with_chip(nand_chip);
+        test.output_list(vec![
+            OutputSpec { id: "a".to_string(), ..Default::default() },
+            OutputSpec { id: "b".to_string(), ..Default::default() },
+            OutputSpec { id: "out".to_string(), ..Default::default() },
+        ]);
+        
+        for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            let mut statement = TestCompoundInstruction::new();
+            statement.add_instruction(Box::new(TestSetInstruction::new("a", a)));
+            statement.add_instruction(Box::new(TestSetInstruction::new("b", b)));
+            statement.add_instruction(Box::new(TestEvalInstruction));
+            statement.add_instruction(Box::new(TestOutputInstruction));
+            test.add_instruction(Box::new(statement));
+        }
+        assert_eq!(test.instructions().len(), 4);
+        
+        let expected = ["| 0 | 0 | 1 |", "| 0 | 1 | 1 |", "| 1 | 0 | 1 |", "| 1 | 1 | 0 |"];
+        for (step, row) in expected.iter().enumerate() {
+            assert_eq!(test.position(), step);
+            assert_eq!(test.step().unwrap(), Some(()));
+            
+            // One new row per step, and the chip can be inspected in between
+            let lines: Vec<&str> = test.log().lines().collect();
+            assert_eq!(lines.len(), step + 1);
+            assert_eq!(lines[step], *row);
+        }
+        
+        assert_eq!(test.step().unwrap(), None);
+        assert_eq!(test.log().lines().count(), 4);
+    }
+    
+    #[test]
+    fn test_composite_state_between_tick_and_tock() {
+        let mut parser = crate::languages::hdl::HdlParser::new().unwrap();
+        let hdl = r#"
+            CHIP Delay {
+                IN in;
+                OUT out;
+                
+                PARTS:
+                DFF(in=in, out=q);
+                Not(in=q, out=nq);
+                Not(in=nq, out=out);
+            }
+        "#;
+        let chip = ChipBuilder::new().build_chip(&parser.parse(hdl).unwrap()).unwrap();
+        let mut test = ChipTest::new().with_chip(chip);
+        test.output_list(vec![
+            OutputSpec { id: "time".to_string(), ..Default::default() },
+            OutputSpec { id: "in".to_string(), ..Default::default() },
+            OutputSpec { id: "out".to_string(), ..Default::default() },
+        ]);
+        
+        // set in 1, tick, output, tock, output
+        test.add_instruction(Box::new(TestSetInstruction::new("in", 1)));
+        test.add_instruction(Box::new(TestTickInstruction));
+        test.add_instruction(Box::new(TestOutputInstruction));
+        test.add_instruction(Box::new(TestTockInstruction));
+        test.add_instruction(Box::new(TestOutputInstruction));
+        
+        while test.step().unwrap().is_some() {}
+        
+        // After tick the DFF has sampled in but the composite still shows the old value
+        let lines: Vec<&str> = test.log().lines().collect();
+        assert_eq!(lines, vec!["| 0+ | 1 | 0 |", "| 1 | 1 | 1 |"]);
+    }
+    
+    #[test]
+    fn test_run_blocking_without_runtime() {
+        let builder = ChipBuilder::new();
+        let nand_chip = builder.build_builtin_chip("Nand").unwrap();
+        let mut test = ChipTest::new().with_chip(nand_chip);
+        test.output_list(vec![
+            OutputSpec { id: "a".to_string(), ..Default::default() },
+            OutputSpec { id: "b".to_string(), ..Default::default() },
+            OutputSpec { id: "out".to_string(), ..Default::default() },
+        ]);
+        
+        for (a, b) in [(0, 0), (1, 1)] {
+            let mut statement = TestCompoundInstruction::new();
+            statement.add_instruction(Box::new(TestSetInstruction::new("a", a)));
+            statement.add_instruction(Box::new(TestSetInstruction::new("b", b)));
+            statement.add_instruction(Box::new(TestEvalInstruction));
+            statement.add_instruction(Box::new(TestOutputInstruction));
+            test.add_instruction(Box::new(statement));
+        }
+        
+        test.run_blocking().unwrap();
+        assert_eq!(test.log(), "| 0 | 0 | 1 |\n| 1 | 1 | 0 |\n");
+    }
+    
+    #[test]
+    fn test_rows_hold_the_output_table_as_cells() {
+        let builder = ChipBuilder::new();
+        let nand_chip = builder.build_builtin_chip("Nand").unwrap();
+        let mut test = ChipTest::new().with_chip(nand_chip);
+        test.output_list(vec![
+            OutputSpec { id: "a".to_string(), ..Default::default() },
+            OutputSpec { id: "b".to_string(), ..Default::default() },
+            OutputSpec { id: "out".to_string(), ..Default::default() },
+        ]);
+        
+        for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            let mut statement = TestCompoundInstruction::new();
+            statement.add_instruction(Box::new(TestSetInstruction::new("a", a)));
+            statement.add_instruction(Box::new(TestSetInstruction::new("b", b)));
+            statement.add_instruction(Box::new(TestEvalInstruction));
+            statement.add_instruction(Box::new(TestOutputInstruction));
+            test.add_instruction(Box::new(statement));
+        }
+        test.run_blocking().unwrap();
+        
+        let rows = test.rows();
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|row| row.len() == 3));
+        assert_eq!(rows[3], vec![" 1 ", " 1 ", " 0 "]);
+        
+        // log() is the same table as text
+        let lines: Vec<String> = rows.iter().map(|row| format!("|{}|", row.join("|"))).collect();
+        assert_eq!(test.log().lines().collect::<Vec<_>>(), lines);
+        
+        // The comparator takes the rows as they are, ignoring padding
+        let comparator = TestComparator::new();
+        let cmp = "|  0  |  0  |  1  |\n|  0  |  1  |  1  |\n|  1  |  0  |  1  |\n|  1  |  1  |  0  |\n";
+        assert!(comparator.row_mismatches(rows, cmp).is_empty());
+        let mismatches = comparator.row_mismatches(rows, &cmp.replace("|  1  |  1  |  0  |", "|  1  |  1  |  1  |"));
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!((mismatches[0].line, mismatches[0].actual.as_str()), (4, "| 1 | 1 | 0 |"));
+    }
+    
+    #[test]
+    #[allow(deprecated)]
+    fn test_append_log_records_rows() {
+        let mut test = ChipTest::new();
+        test.append_log("| a | b |\n|  0  |  1  |\n");
+        assert_eq!(test.rows(), &[vec![" a ", " b "], vec!["  0  ", "  1  "]]);
+        assert_eq!(test.log(), "| a | b |\n|  0  |  1  |\n");
+        
+        // Anything that isn't a whole table line only reaches the log
+        test.append_log("note");
+        assert_eq!(test.rows().len(), 2);
+        assert!(test.log().ends_with("note"));
+    }
+}
@@ -1,26 +1,86 @@
-// Test comparator module - stub implementation
-// This will be expanded to handle CMP file comparison
+// Test comparator module
+// Compares a test's output table against a .cmp file cell by cell, ignoring column padding
 
 use crate::error::Result;
+use super::chiptst::render_row;
+
+/// One differing line between the actual output and the compare file (1-based)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub line: usize,
+    pub expected: String,
+    pub actual: String,
+}
 
 #[derive(Debug)]
 pub struct TestComparator {
-    // Placeholder for test comparator implementation
+    // Stateless; the comparison rules are fixed by the course format
 }
 
 impl TestComparator {
     pub fn new() -> Self {
         Self {}
     }
-    
-    pub fn compare_output(&self, _actual: &str, _expected: &str) -> Result<bool> {
-        // TODO: Implement output comparison logic
-        todo!("Output comparison not yet implemented")
+
+    pub fn compare_output(&self, actual: &str, expected: &str) -> Result<bool> {
+        Ok(self.mismatches(actual, expected).is_empty())
+    }
+
+    /// Every line whose trimmed cells differ, plus lines present on only one side
+    pub fn mismatches(&self, actual: &str, expected: &str) -> Vec<Mismatch> {
+        let actual_lines: Vec<&str> = actual.trim_end().lines().collect();
+        let expected_lines: Vec<&str> = expected.trim_end().lines().collect();
+
+        (0..actual_lines.len().max(expected_lines.len()))
+            .filter_map(|i| {
+                let actual = actual_lines.get(i).copied().unwrap_or("");
+                let expected = expected_lines.get(i).copied().unwrap_or("");
+                (cells(actual) != cells(expected)).then(|| Mismatch {
+                    line: i + 1,
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                })
+            })
+            .collect()
     }
+
+    /// Like `mismatches`, but taking the actual output as `ChipTest::rows` so its cells are
+    /// compared as they are instead of being re-split from the log text
+    pub fn row_mismatches(&self, actual: &[Vec<String>], expected: &str) -> Vec<Mismatch> {
+        let expected_lines: Vec<&str> = expected.trim_end().lines().collect();
+
+        (0..actual.len().max(expected_lines.len()))
+            .filter_map(|i| {
+                let row = actual.get(i);
+                let expected = expected_lines.get(i).copied().unwrap_or("");
+                let actual_cells: Vec<&str> = row.into_iter().flatten().map(|cell| cell.trim()).collect();
+                (actual_cells != inner_cells(expected)).then(|| Mismatch {
+                    line: i + 1,
+                    expected: expected.to_string(),
+                    actual: row.map(|row| render_row(row)).unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+}
+
+fn cells(line: &str) -> Vec<&str> {
+    line.trim().split('|').map(str::trim).collect()
+}
+
+// The cells between a line's outer bars; none for a blank line
+fn inner_cells(line: &str) -> Vec<&str> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Vec::new();
+    }
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+    line.split('|').map(str::trim).collect()
 }
 
 impl Default for TestComparator {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
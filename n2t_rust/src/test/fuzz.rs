@@ -0,0 +1,158 @@
+// Randomized wiring harness for stress-testing the builder, wire and subbus machinery
+// Compositions are generated as HDL so the whole parse -> build -> eval path is exercised
+
+use crate::chip::builder::ChipBuilder;
+use crate::chip::ChipInterface;
+use crate::languages::hdl::HdlParser;
+
+// A gate the generator can place: name, input pins with widths, output width
+type GateSpec = (&'static str, &'static [(&'static str, usize)], usize);
+
+const GATES: &[GateSpec] = &[
+    ("Nand", &[("a", 1), ("b", 1)], 1),
+    ("And", &[("a", 1), ("b", 1)], 1),
+    ("Or", &[("a", 1), ("b", 1)], 1),
+    ("Xor", &[("a", 1), ("b", 1)], 1),
+    ("Not", &[("in", 1)], 1),
+    ("Mux", &[("a", 1), ("b", 1), ("sel", 1)], 1),
+    ("Not16", &[("in", 16)], 16),
+    ("And16", &[("a", 16), ("b", 16)], 16),
+    ("Or16", &[("a", 16), ("b", 16)], 16),
+    ("Add16", &[("a", 16), ("b", 16)], 16),
+    ("Inc16", &[("in", 16)], 16),
+    ("Mux16", &[("a", 16), ("b", 16), ("sel", 1)], 16),
+    ("Equal16", &[("a", 16), ("b", 16)], 1),
+    ("OrReduce16", &[("in", 16)], 1),
+];
+
+/// Small deterministic xorshift generator so compositions are reproducible from a seed
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // xorshift must not start from zero
+        Self(seed ^ 0x9e37_79b9_7f4a_7c15 | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// HDL source of the random composition for `seed`, useful for reproducing a failure
+pub fn random_composite_hdl(seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+
+    // Signals available to later parts: (name, width)
+    let mut signals: Vec<(String, usize)> = Vec::new();
+    let mut inputs = Vec::new();
+    for i in 0..1 + rng.below(3) {
+        signals.push((format!("i{}", i), 1));
+        inputs.push(format!("i{}", i));
+    }
+    for i in 0..1 + rng.below(2) {
+        signals.push((format!("x{}", i), 16));
+        inputs.push(format!("x{}[16]", i));
+    }
+
+    let part_count = 2 + rng.below(7);
+    let mut parts = Vec::new();
+    let mut out_width = 1;
+    for p in 0..part_count {
+        let (gate, pins, width) = GATES[rng.below(GATES.len())];
+        let mut wires = Vec::new();
+        for &(pin, pin_width) in pins {
+            wires.push(format!("{}={}", pin, pick_source(&mut rng, &signals, pin_width)));
+        }
+
+        // Parts only read earlier signals, so the network is acyclic; the last one drives the output
+        let target = if p + 1 == part_count {
+            out_width = width;
+            "out".to_string()
+        } else {
+            format!("t{}", p)
+        };
+        wires.push(format!("out={}", target));
+        parts.push(format!("{}({});", gate, wires.join(", ")));
+        signals.push((target, width));
+    }
+
+    let out = if out_width == 1 { "out".to_string() } else { format!("out[{}]", out_width) };
+    format!(
+        "CHIP Fuzz{} {{\n    IN {};\n    OUT {};\n    PARTS:\n    {}\n}}\n",
+        seed,
+        inputs.join(", "),
+        out,
+        parts.join("\n    ")
+    )
+}
+
+// Source for a part input: a signal of matching width, one bit of a wide signal, or a constant
+fn pick_source(rng: &mut Rng, signals: &[(String, usize)], width: usize) -> String {
+    if rng.below(8) == 0 {
+        return if rng.below(2) == 0 { "true" } else { "false" }.to_string();
+    }
+
+    let readable: Vec<&(String, usize)> = signals.iter().filter(|(name, _)| name != "out").collect();
+    let candidates: Vec<&&(String, usize)> = readable.iter()
+        .filter(|(_, signal_width)| *signal_width == width || width == 1)
+        .collect();
+    let (name, signal_width) = candidates[rng.below(candidates.len())];
+    if *signal_width == width {
+        name.clone()
+    } else {
+        format!("{}[{}]", name, rng.below(*signal_width))
+    }
+}
+
+/// Build the random combinational composition of builtin gates for `seed`
+pub fn random_composite(seed: u64) -> Box<dyn ChipInterface> {
+    let hdl = random_composite_hdl(seed);
+    let hdl_chip = HdlParser::new()
+        .and_then(|mut parser| parser.parse(&hdl))
+        .unwrap_or_else(|e| panic!("generated HDL failed to parse ({}):\n{}", e, hdl));
+    ChipBuilder::new()
+        .build_chip(&hdl_chip)
+        .unwrap_or_else(|e| panic!("generated HDL failed to build ({}):\n{}", e, hdl))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_composites_settle() {
+        for seed in 0..300 {
+            let mut chip = random_composite(seed);
+            let mut rng = Rng::new(seed.wrapping_add(1));
+
+            for _ in 0..4 {
+                for name in chip.input_pin_names_sorted() {
+                    chip.set_pin(&name, rng.next() as u16).unwrap();
+                }
+
+                // The networks are acyclic, so one eval settles and the next confirms it
+                let evals = chip.eval_settle(3)
+                    .unwrap_or_else(|e| panic!("{}\n{}", e, random_composite_hdl(seed)));
+                assert!(evals <= 2, "seed {} took {} evals", seed, evals);
+
+                let settled = chip.read_pin("out").unwrap();
+                chip.eval().unwrap();
+                assert_eq!(chip.read_pin("out").unwrap(), settled, "seed {} is unstable", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_composite_is_reproducible() {
+        assert_eq!(random_composite_hdl(42), random_composite_hdl(42));
+        assert_ne!(random_composite_hdl(42), random_composite_hdl(43));
+    }
+}
@@ -0,0 +1,127 @@
+// March tests for RAM chips: drive the chip through its address/in/load pins and clock edges
+// and check every word reads back what the pattern last wrote
+
+use crate::chip::ChipInterface;
+use crate::chip::pin::{HIGH, LOW};
+use crate::error::{Result, SimulatorError};
+
+/// A read during `march_test` that didn't return the value the pattern had left there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarchFault {
+    /// Index of the March C- element the read belongs to (0..6)
+    pub element: usize,
+    pub address: usize,
+    pub expected: u16,
+    pub actual: u16,
+}
+
+// March C- as (ascending, value to read first, value to write after) per element:
+// ⇕(w0) ⇑(r0,w1) ⇑(r1,w0) ⇓(r0,w1) ⇓(r1,w0) ⇕(r0), with all-zero and all-one words
+const MARCH_C_MINUS: [(bool, Option<bool>, Option<bool>); 6] = [
+    (true, None, Some(false)),
+    (true, Some(false), Some(true)),
+    (true, Some(true), Some(false)),
+    (false, Some(false), Some(true)),
+    (false, Some(true), Some(false)),
+    (true, Some(false), None),
+];
+
+/// Run March C- over addresses 0..size of a RAM with `in`, `load`, `address` and `out` pins,
+/// returning every faulty read; catches stuck-at, transition, coupling and address decoder faults
+/// Writes take a full clock cycle (eval, tick, tock); reads are combinational
+/// Errors if the chip has no clock, lacks one of the pins or `size` exceeds its address space
+pub fn march_test(chip: &mut dyn ChipInterface, size: usize) -> Result<Vec<MarchFault>> {
+    if chip.as_clocked_mut().is_none() {
+        return Err(SimulatorError::Test(format!("march test needs a clocked chip, {} is not", chip.name())));
+    }
+    let address_width = chip.pin_width("address")?;
+    if size > 1 << address_width {
+        return Err(SimulatorError::Test(format!(
+            "march test over {} words, but {} has a {}-bit address", size, chip.name(), address_width
+        )));
+    }
+    let data_width = chip.pin_width("in")?;
+    let ones = if data_width >= 16 { 0xffff } else { (1u16 << data_width) - 1 };
+    let word = |bit: bool| if bit { ones } else { 0 };
+
+    let mut faults = Vec::new();
+    for (element, &(ascending, read, write)) in MARCH_C_MINUS.iter().enumerate() {
+        let addresses: Box<dyn Iterator<Item = usize>> = if ascending {
+            Box::new(0..size)
+        } else {
+            Box::new((0..size).rev())
+        };
+        for address in addresses {
+            if let Some(bit) = read {
+                let actual = read_word(chip, address)?;
+                if actual != word(bit) {
+                    faults.push(MarchFault { element, address, expected: word(bit), actual });
+                }
+            }
+            if let Some(bit) = write {
+                write_word(chip, address, word(bit))?;
+            }
+        }
+    }
+    Ok(faults)
+}
+
+fn read_word(chip: &mut dyn ChipInterface, address: usize) -> Result<u16> {
+    chip.set_pin("address", address as u16)?;
+    chip.set_pin("load", LOW as u16)?;
+    chip.eval()?;
+    chip.read_pin("out")
+}
+
+fn write_word(chip: &mut dyn ChipInterface, address: usize, value: u16) -> Result<()> {
+    chip.set_pin("address", address as u16)?;
+    chip.set_pin("in", value)?;
+    chip.set_pin("load", HIGH as u16)?;
+    chip.eval()?;
+    let clocked = chip.as_clocked_mut().expect("checked clocked before the march");
+    clocked.tick(HIGH)?;
+    clocked.tock(LOW)?;
+    chip.set_pin("load", LOW as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chip::builder::ChipBuilder;
+    use crate::languages::hdl::HdlParser;
+
+    #[test]
+    fn test_march_ram64_and_ram512() {
+        let builder = ChipBuilder::new();
+        for (name, size) in [("RAM64", 64), ("RAM512", 512)] {
+            let mut ram = builder.build_builtin_chip(name).unwrap();
+            assert_eq!(march_test(ram.as_mut(), size).unwrap(), vec![], "{} should have no faults", name);
+        }
+    }
+
+    #[test]
+    fn test_march_finds_stuck_at_bit() {
+        let mut parser = HdlParser::new().unwrap();
+        let hdl = r#"
+            CHIP StuckRAM8 {
+                IN in[16], load, address[3];
+                OUT out[16];
+
+                PARTS:
+                RAM8(in=in, load=load, address=address, out=ram);
+                Or16(a=ram, b[0]=true, out=out);
+            }
+        "#;
+        let mut ram = ChipBuilder::new().build_chip(&parser.parse(hdl).unwrap()).unwrap();
+
+        // Bit 0 always reads 1, so every read of a zero word fails
+        let faults = march_test(ram.as_mut(), 8).unwrap();
+        assert_eq!(faults.len(), 3 * 8);
+        assert_eq!(faults[0], MarchFault { element: 1, address: 0, expected: 0, actual: 1 });
+
+        let mut not = ChipBuilder::new().build_builtin_chip("Not").unwrap();
+        assert!(march_test(not.as_mut(), 1).is_err());
+        let mut ram8 = ChipBuilder::new().build_builtin_chip("RAM8").unwrap();
+        assert!(march_test(ram8.as_mut(), 9).is_err());
+    }
+}
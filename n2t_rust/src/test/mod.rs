@@ -4,11 +4,19 @@ pub mod chiptst;
 pub mod runner;
 pub mod comparator;
 pub mod harness;
+pub mod session;
+pub mod truth_table;
+pub mod memory;
 
 #[cfg(test)]
 mod chiptst_tests;
+#[cfg(test)]
+mod fuzz;
 
-pub use chiptst::{ChipTest, OutputSpec, TestInstruction, TestSetInstruction, TestEvalInstruction, TestOutputInstruction, TestTickInstruction, TestTockInstruction, TestCompoundInstruction};
+pub use chiptst::{ChipTest, OutputSpec, TestInstruction, TestSetInstruction, TestEvalInstruction, TestOutputInstruction, TestOutputListInstruction, TestTickInstruction, TestTockInstruction, TestCompoundInstruction, TestCondition, TestWhileInstruction};
 pub use runner::TestRunner;
-pub use comparator::TestComparator;
-pub use harness::TestHarness;
\ No newline at end of file
+pub use comparator::{Mismatch, TestComparator};
+pub use harness::TestHarness;
+pub use session::{Session, SessionResult};
+pub use truth_table::{truth_table, input_combinations, MAX_TRUTH_TABLE_ROWS};
+pub use memory::{march_test, MarchFault};
\ No newline at end of file
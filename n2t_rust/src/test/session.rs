@@ -0,0 +1,207 @@
+// End-to-end test session: HDL library + .tst script + optional .cmp file
+// This is the path a grading tool takes: parse the chips, run the script, compare the output
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::chip::builder::ChipBuilder;
+use crate::error::{Result, SimulatorError};
+use crate::languages::hdl::{HdlChip, HdlParser};
+use crate::languages::tst::TstParser;
+use super::comparator::{Mismatch, TestComparator};
+
+/// Outcome of one script run
+#[derive(Debug, Clone)]
+pub struct SessionResult {
+    pub chip: String,
+    /// The output table the script produced
+    pub output: String,
+    /// Whether the output was checked against a compare file
+    pub compared: bool,
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl SessionResult {
+    pub fn passed(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Session {
+    library: HashMap<String, HdlChip>,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse every `.hdl` file in `dir` into the chip library
+    pub fn load_hdl_dir(mut self, dir: impl AsRef<Path>) -> Result<Self> {
+        let mut parser = HdlParser::new()?;
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "hdl") {
+                let chip = parser.parse(&std::fs::read_to_string(&path)?)
+                    .map_err(|e| SimulatorError::Parse(format!("{}: {}", path.display(), e)))?;
+                self.library.insert(chip.name.clone(), chip);
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn library(&self) -> &HashMap<String, HdlChip> {
+        &self.library
+    }
+
+    /// Run the script at `path` against the chip it loads, comparing with its `compare-to`
+    /// file, or without that directive the `.cmp` file of the same name when one exists
+    /// Paths in the script resolve relative to the script's directory
+    pub fn run_tst(&self, path: impl AsRef<Path>) -> Result<SessionResult> {
+        let path = path.as_ref();
+        let mut test = TstParser::new().parse(&std::fs::read_to_string(path)?)?;
+
+        let load_file = test.load_file()
+            .ok_or_else(|| SimulatorError::Test(format!("{} does not load a chip", path.display())))?;
+        let name = load_file.strip_suffix(".hdl").unwrap_or(load_file).to_string();
+        let definition = self.library.get(&name)
+            .ok_or_else(|| SimulatorError::Test(format!("Chip {} is not in the loaded library", name)))?;
+
+        let builder = ChipBuilder::new().with_library(self.library.values().cloned());
+        let chip = builder.build_chip(definition)?;
+        test = test.with_chip(chip);
+        test.run_blocking()?;
+
+        let output = test.log().to_string();
+        let cmp_path = match test.compare_to() {
+            Some(file) => path.with_file_name(file),
+            None => path.with_extension("cmp"),
+        };
+        let (compared, mismatches) = if test.compare_to().is_some() || cmp_path.exists() {
+            let expected = std::fs::read_to_string(&cmp_path)?;
+            (true, TestComparator::new().row_mismatches(test.rows(), &expected))
+        } else {
+            (false, Vec::new())
+        };
+
+        Ok(SessionResult { chip: name, output, compared, mismatches })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOT_HDL: &str = "CHIP Not {\n    IN in;\n    OUT out;\n\n    PARTS:\n    Nand(a=in, b=in, out=out);\n}\n";
+    const NOT_TST: &str = "load Not.hdl,\noutput-file Not.out,\ncompare-to Not.cmp,\noutput-list in%B3.1.3 out%B3.1.3;\n\nset in 0,\neval,\noutput;\n\nset in 1,\neval,\noutput;\n";
+    const NOT_CMP: &str = "|  in   |  out  |\n|   0   |   1   |\n|   1   |   0   |\n";
+
+    /// A temp directory of fixture files, removed on drop so a failing test doesn't leave it behind
+    struct FixtureDir(std::path::PathBuf);
+
+    impl std::ops::Deref for FixtureDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for FixtureDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn fixture_dir(name: &str, files: &[(&str, &str)]) -> FixtureDir {
+        let dir = FixtureDir(std::env::temp_dir().join(format!("session_{}_{}", name, std::process::id())));
+        std::fs::create_dir_all(&*dir).unwrap();
+        for (file, contents) in files {
+            std::fs::write(dir.join(file), contents).unwrap();
+        }
+        dir
+    }
+
+    // The Not fixture, compared against `cmp`
+    fn not_files(cmp: &str) -> [(&'static str, &str); 3] {
+        [("Not.hdl", NOT_HDL), ("Not.tst", NOT_TST), ("Not.cmp", cmp)]
+    }
+
+    #[test]
+    fn test_session_runs_not_fixture() {
+        let dir = fixture_dir("pass", &not_files(NOT_CMP));
+        let result = Session::new().load_hdl_dir(&*dir).unwrap().run_tst(dir.join("Not.tst")).unwrap();
+
+        assert_eq!(result.chip, "Not");
+        assert!(result.compared);
+        assert!(result.passed(), "unexpected mismatches: {:?}", result.mismatches);
+        assert_eq!(result.output.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_session_compares_against_compare_to_file() {
+        // Not.cmp is wrong, but the script names the correct file
+        let wrong = NOT_CMP.replace("|   1   |   0   |", "|   1   |   1   |");
+        let tst = NOT_TST.replace("compare-to Not.cmp", "compare-to Golden.cmp");
+        let dir = fixture_dir("compare_to", &[("Not.hdl", NOT_HDL), ("Not.tst", &tst), ("Not.cmp", &wrong), ("Golden.cmp", NOT_CMP)]);
+        let result = Session::new().load_hdl_dir(&*dir).unwrap().run_tst(dir.join("Not.tst")).unwrap();
+
+        assert!(result.compared);
+        assert!(result.passed(), "unexpected mismatches: {:?}", result.mismatches);
+    }
+
+    #[test]
+    fn test_session_round_trip_and_from_nand() {
+        // And built from Nand and the library's own Not, which is itself built from Nand
+        let and_hdl = r#"
+            // And gate: out = a And b
+            CHIP And {
+                IN a, b;
+                OUT out;
+
+                PARTS:
+                Nand(a=a, b=b, out=nandOut);
+                Not(in=nandOut, out=out);
+            }
+        "#;
+        let and_tst = r#"
+            load And.hdl,
+            output-file And.out,
+            compare-to And.cmp,
+            output-list a%B3.1.3 b%B3.1.3 out%B3.1.3;
+
+            set a 0, set b 0, eval, output;
+            set a 0, set b 1, eval, output;
+            set a 1, set b 0, eval, output;
+            set a 1, set b 1, eval, output;
+        "#;
+        let and_cmp = "|   a   |   b   |  out  |\n\
+                       |   0   |   0   |   0   |\n\
+                       |   0   |   1   |   0   |\n\
+                       |   1   |   0   |   0   |\n\
+                       |   1   |   1   |   1   |\n";
+
+        let dir = fixture_dir("and", &[("Not.hdl", NOT_HDL), ("And.hdl", and_hdl), ("And.tst", and_tst), ("And.cmp", and_cmp)]);
+        let session = Session::new().load_hdl_dir(&*dir).unwrap();
+        let result = session.run_tst(dir.join("And.tst")).unwrap();
+
+        assert_eq!(session.library().len(), 2);
+        assert_eq!(result.chip, "And");
+        assert!(result.compared);
+        assert!(result.passed(), "unexpected mismatches: {:?}", result.mismatches);
+        assert!(result.mismatches.is_empty());
+        assert_eq!(result.output.lines().count(), 5);
+    }
+
+    #[test]
+    fn test_session_reports_mismatched_rows() {
+        let wrong = NOT_CMP.replace("|   1   |   0   |", "|   1   |   1   |");
+        let dir = fixture_dir("fail", &not_files(&wrong));
+        let result = Session::new().load_hdl_dir(&*dir).unwrap().run_tst(dir.join("Not.tst")).unwrap();
+
+        assert!(!result.passed());
+        assert_eq!(result.mismatches.len(), 1);
+        assert_eq!(result.mismatches[0].line, 3);
+    }
+}
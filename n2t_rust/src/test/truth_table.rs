@@ -0,0 +1,76 @@
+// Exhaustive truth tables of combinational chips, for docs, comparison and caching
+
+use crate::chip::ChipInterface;
+
+/// Most rows `truth_table` will enumerate; wider inputs are cut off after this many rows
+pub const MAX_TRUTH_TABLE_ROWS: usize = 1 << 16;
+
+/// Every combination of values for inputs of the given widths, counting up with the first
+/// input as the most significant, stopping after `cap` combinations
+pub fn input_combinations(widths: &[usize], cap: usize) -> impl Iterator<Item = Vec<u16>> + '_ {
+    let total_bits: usize = widths.iter().sum();
+    let rows = 1u64.checked_shl(total_bits as u32).unwrap_or(u64::MAX);
+
+    (0..rows.min(cap as u64)).map(move |mut packed| {
+        let mut values = vec![0u16; widths.len()];
+        for (value, &width) in values.iter_mut().zip(widths).rev() {
+            *value = (packed & ((1u64 << width) - 1)) as u16;
+            packed >>= width;
+        }
+        values
+    })
+}
+
+/// Drive `inputs` through every combination of values (up to MAX_TRUTH_TABLE_ROWS rows), eval,
+/// and record `outputs`: one (input values, output values) row per combination
+/// Panics if a pin is missing or an eval fails, as a table of a broken chip is meaningless
+pub fn truth_table(chip: &mut dyn ChipInterface, inputs: &[&str], outputs: &[&str]) -> Vec<(Vec<u16>, Vec<u16>)> {
+    let widths: Vec<usize> = inputs.iter()
+        .map(|name| chip.pin_width(name).unwrap_or_else(|e| panic!("truth table input: {}", e)))
+        .collect();
+
+    input_combinations(&widths, MAX_TRUTH_TABLE_ROWS)
+        .map(|values| {
+            for (name, value) in inputs.iter().zip(&values) {
+                chip.set_pin(name, *value).unwrap_or_else(|e| panic!("truth table input: {}", e));
+            }
+            chip.eval().unwrap_or_else(|e| panic!("truth table eval of {}: {}", chip.name(), e));
+            let results = outputs.iter()
+                .map(|name| chip.read_pin(name).unwrap_or_else(|e| panic!("truth table output: {}", e)))
+                .collect();
+            (values, results)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chip::builder::ChipBuilder;
+
+    #[test]
+    fn test_and_truth_table() {
+        let mut and = ChipBuilder::new().build_builtin_chip("And").unwrap();
+        let table = truth_table(and.as_mut(), &["a", "b"], &["out"]);
+
+        assert_eq!(table, vec![
+            (vec![0, 0], vec![0]),
+            (vec![0, 1], vec![0]),
+            (vec![1, 0], vec![0]),
+            (vec![1, 1], vec![1]),
+        ]);
+    }
+
+    #[test]
+    fn test_input_combinations_order_and_cap() {
+        let rows: Vec<Vec<u16>> = input_combinations(&[1, 2], 100).collect();
+        assert_eq!(rows.len(), 8);
+        assert_eq!(rows[1], vec![0, 1]);
+        assert_eq!(rows[4], vec![1, 0]);
+        assert_eq!(rows[7], vec![1, 3]);
+
+        // Two 16-bit inputs stop at the cap
+        assert_eq!(input_combinations(&[16, 16], MAX_TRUTH_TABLE_ROWS).count(), MAX_TRUTH_TABLE_ROWS);
+        assert_eq!(input_combinations(&[16, 16], 3).last(), Some(vec![0, 2]));
+    }
+}